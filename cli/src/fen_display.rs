@@ -13,12 +13,13 @@ pub fn generate_display_from_fen(fen: &str) -> [String; 10] {
     ];
 
     let fen = fen.split(' ').next().unwrap();
+    let fen = fen.split('[').next().unwrap();
     let parts = fen.split('/');
     for (rank_index, part) in parts.enumerate() {
         let line = &mut result[rank_index];
 
         let mut file_index = 0;
-        for c in part.chars() {
+        for c in part.chars().filter(|c| *c != '~') {
             if !c.is_ascii_digit() {
                 line.push(glyph_for(c));
                 if file_index != 7 {
@@ -46,6 +47,24 @@ pub fn generate_display_from_fen(fen: &str) -> [String; 10] {
     result
 }
 
+pub fn generate_pocket_display_from_fen(fen: &str) -> Option<String> {
+    let board = fen.split(' ').next().unwrap();
+    let pocket = board.split('[').nth(1)?.trim_end_matches(']');
+
+    let first_player: String = pocket
+        .chars()
+        .filter(|c| c.is_ascii_uppercase())
+        .map(glyph_for)
+        .collect();
+    let second_player: String = pocket
+        .chars()
+        .filter(|c| c.is_ascii_lowercase())
+        .map(glyph_for)
+        .collect();
+
+    Some(format!("Pockets: {} | {}", first_player, second_player))
+}
+
 fn glyph_for(c: char) -> char {
     match c {
         'R' => '♖',
@@ -114,4 +133,31 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn generate_crazyhouse_game_view_ignores_pocket_and_promotion_markers() {
+        let result = generate_display_from_fen(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBN~R[Pq] w KQkq - 0 1",
+        );
+
+        assert_eq!(result[7], String::from("1| ♖ ♘ ♗ ♕ ♔ ♗ ♘ ♖"));
+    }
+
+    #[test]
+    fn generate_pocket_view() {
+        let result = generate_pocket_display_from_fen(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[PNq] w KQkq - 0 1",
+        );
+
+        assert_eq!(Some(String::from("Pockets: ♙♘ | ♛")), result);
+    }
+
+    #[test]
+    fn given_no_pocket_generate_pocket_view_returns_none() {
+        let result = generate_pocket_display_from_fen(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        );
+
+        assert_eq!(None, result);
+    }
 }
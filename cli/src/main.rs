@@ -2,21 +2,32 @@ mod fen_display;
 
 use std::io::Write;
 
-use crate::fen_display::generate_display_from_fen;
+use crate::fen_display::{generate_display_from_fen, generate_pocket_display_from_fen};
 use console::Term;
 use iroh::game::Game;
+use iroh::state::variant::Variant;
 use iroh::state::GameState;
 
 fn main() {
+    let variant = match std::env::args().nth(1).map(|name| name.parse::<Variant>()) {
+        Some(Ok(variant)) => variant,
+        Some(Err(error)) => {
+            let names: Vec<String> = Variant::ALL.iter().map(Variant::to_string).collect();
+            eprintln!("{error}");
+            eprintln!("Accepted variants: {}", names.join(", "));
+            std::process::exit(1);
+        }
+        None => Variant::default(),
+    };
     let mut term = Term::stdout();
     term.clear_screen().unwrap();
-    let mut game = Game::new();
+    let mut game = Game::with_variant(variant);
     let mut input = String::new();
     render(&term, &game, game.generate_fen().unwrap());
 
     loop {
         ask_for_next_move(&mut term, &mut input);
-        game = game.make_move_san(&*input);
+        game = game.make_move_san(&input);
         match &game {
             Game::Ongoing { .. } => {
                 render(&term, &game, game.generate_fen().unwrap());
@@ -75,6 +86,9 @@ fn render(term: &Term, game: &Game, fen: String) {
     for line in display {
         term.write_line(&line[..]).unwrap();
     }
+    if let Some(pocket) = generate_pocket_display_from_fen(&fen[..]) {
+        term.write_line(&pocket[..]).unwrap();
+    }
 
     term.write_line("").unwrap();
     term.write_line(game.generate_pgn().unwrap().as_str())
@@ -2,7 +2,11 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 use iroh::game::Game;
 use iroh::search::search;
-use iroh::state::GameState;
+use iroh::search::search_limits::SearchLimits;
+use iroh::search::search_observer::NullObserver;
+use iroh::search::search_options::SearchOptions;
+use iroh::search::search_with_options;
+use iroh::search::transposition_table::TranspositionTable;
 
 fn search_basic(c: &mut Criterion) {
     let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
@@ -16,5 +20,56 @@ fn search_complex(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, search_basic, search_complex);
+fn search_move_ordering(c: &mut Criterion) {
+    let mut game = Game::from_fen("r3k3/1p6/3q4/8/8/3Q4/1P6/R3K3 w - - 0 1");
+    let limits = SearchLimits::new();
+    let mut group = c.benchmark_group("search_move_ordering");
+    for (name, options) in [
+        ("ordered", SearchOptions::new()),
+        ("unordered", SearchOptions::new().with_move_ordering(false)),
+    ] {
+        let nodes = search_with_options(
+            &mut game,
+            &limits,
+            &options,
+            &mut TranspositionTable::default(),
+            NullObserver,
+        )
+        .unwrap()
+        .nodes;
+        println!("search_move_ordering/{name}: {nodes} nodes");
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                search_with_options(
+                    black_box(&mut game),
+                    &limits,
+                    &options,
+                    &mut TranspositionTable::default(),
+                    NullObserver,
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+fn search_no_move(c: &mut Criterion) {
+    let mut group = c.benchmark_group("search_no_move");
+    for (name, fen) in [
+        ("checkmate", "k6R/8/1K6/8/8/8/8/8 b - - 0 1"),
+        ("stalemate", "k7/8/1QK5/8/8/8/8/8 b - - 0 1"),
+    ] {
+        let mut game = Game::from_fen(fen);
+        group.bench_function(name, |b| b.iter(|| search(black_box(&mut game))));
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    search_basic,
+    search_complex,
+    search_move_ordering,
+    search_no_move
+);
 criterion_main!(benches);
@@ -1,2 +0,0 @@
-#[derive(Debug)]
-pub struct IllegalMoveError {}
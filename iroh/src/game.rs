@@ -1,6 +1,8 @@
 use crate::moves::Move;
 use crate::serialisers::pgn::generate_pgn;
 use crate::state::captured_pieces::CapturedPieces;
+use crate::state::tile::Tile;
+use crate::state::variant::Variant;
 use crate::state::GameState;
 
 #[derive(Clone)]
@@ -27,9 +29,20 @@ impl Game {
         }
     }
 
+    pub fn with_variant(variant: Variant) -> Game {
+        Game::Ongoing {
+            state: GameState::with_variant(variant),
+        }
+    }
+
     pub fn from_fen(fen: &str) -> Game {
         let game_state = GameState::from_fen(fen);
-        game_state.determine_status()
+        Game::from_state(game_state)
+    }
+
+    pub fn from_fen_with_variant(fen: &str, variant: Variant) -> Game {
+        let game_state = GameState::from_fen_with_variant(fen, variant);
+        Game::from_state(game_state)
     }
 
     pub fn unwrap_if_ongoing(self) -> GameState {
@@ -77,41 +90,61 @@ impl Game {
         })
     }
 
+    pub(crate) fn make_null_move(&self) -> Game {
+        Game::from_state(self.unwrap().make_null_move())
+    }
+
     fn determine_status(&self, state: Option<GameState>) -> Game {
         if let Some(state) = state {
-            if state.possible_moves.is_empty() {
-                if state.is_check(state.is_first_player_turn) {
-                    Game::Win {
-                        is_first_player_win: !state.is_first_player_turn(),
-                        state,
-                    }
-                } else {
-                    Game::Draw { state }
+            Game::from_state(state)
+        } else {
+            Game::IllegalMove {
+                state: self.unwrap().clone(),
+            }
+        }
+    }
+
+    fn from_state(state: GameState) -> Game {
+        if let Some(outcome) = determine_variant_outcome(&state) {
+            match outcome {
+                VariantOutcome::Win {
+                    is_first_player_win,
+                } => Game::Win {
+                    is_first_player_win,
+                    state,
+                },
+                VariantOutcome::Draw => Game::Draw { state },
+            }
+        } else if state.possible_moves.is_empty() {
+            if state.is_check(state.is_first_player_turn) {
+                Game::Win {
+                    is_first_player_win: !state.is_first_player_turn(),
+                    state,
                 }
             } else {
-                let mut is_first_player_turn = !state.is_first_player_turn;
-                let mut first_player_sans = vec![];
-                let mut second_player_sans = vec![];
-                for san in state.sans.clone().into_iter().rev() {
-                    if is_first_player_turn {
-                        first_player_sans.push(san);
-                    } else {
-                        second_player_sans.push(san);
-                    }
-                    is_first_player_turn = !is_first_player_turn;
-                }
-
-                if (!state.is_first_player_turn && state.is_fivefold_repetition(&first_player_sans))
-                    || state.is_fivefold_repetition(&second_player_sans)
-                    || state.turn_number - state.captured_pieces.last_capture_turn >= 75
-                {
-                    Game::Draw { state }
+                Game::Draw { state }
+            }
+        } else {
+            let mut is_first_player_turn = !state.is_first_player_turn;
+            let mut first_player_sans = vec![];
+            let mut second_player_sans = vec![];
+            for san in state.sans.clone().into_iter().rev() {
+                if is_first_player_turn {
+                    first_player_sans.push(san);
                 } else {
-                    Game::Ongoing { state }
+                    second_player_sans.push(san);
                 }
+                is_first_player_turn = !is_first_player_turn;
+            }
+
+            if (!state.is_first_player_turn && state.is_fivefold_repetition(&first_player_sans))
+                || state.is_fivefold_repetition(&second_player_sans)
+                || state.turn_number - state.captured_pieces.last_capture_turn >= 75
+            {
+                Game::Draw { state }
+            } else {
+                Game::Ongoing { state }
             }
-        } else {
-            Game::IllegalMove { state: self.unwrap().clone() }
         }
     }
 
@@ -144,6 +177,65 @@ impl Game {
     }
 }
 
+enum VariantOutcome {
+    Win { is_first_player_win: bool },
+    Draw,
+}
+
+///Some variants end by something other than checkmate or stalemate
+fn determine_variant_outcome(state: &GameState) -> Option<VariantOutcome> {
+    let win = |is_first_player_win| {
+        Some(VariantOutcome::Win {
+            is_first_player_win,
+        })
+    };
+    match state.variant {
+        Variant::Atomic if !state.has_king(true) => win(false),
+        Variant::Atomic if !state.has_king(false) => win(true),
+        //Running out of moves, by losing every piece or being stalemated, wins Antichess
+        Variant::Antichess if state.possible_moves.is_empty() => win(state.is_first_player_turn()),
+        Variant::ThreeCheck if state.first_player_checks_given >= 3 => win(true),
+        Variant::ThreeCheck if state.second_player_checks_given >= 3 => win(false),
+        Variant::KingOfTheHill if state.is_king_on_hill(true) => win(true),
+        Variant::KingOfTheHill if state.is_king_on_hill(false) => win(false),
+        Variant::Horde if !state.has_pieces(true) => win(false),
+        Variant::RacingKings => determine_racing_kings_outcome(state),
+        _ => None,
+    }
+}
+
+///The second player gets one more move after the first player reaches the last rank, and draws
+///if they reach it too
+fn determine_racing_kings_outcome(state: &GameState) -> Option<VariantOutcome> {
+    match (
+        state.is_king_on_last_rank(true),
+        state.is_king_on_last_rank(false),
+    ) {
+        (true, true) => Some(VariantOutcome::Draw),
+        (false, true) => Some(VariantOutcome::Win {
+            is_first_player_win: false,
+        }),
+        (true, false)
+            if state.is_first_player_turn() || !can_second_player_king_reach_last_rank(state) =>
+        {
+            Some(VariantOutcome::Win {
+                is_first_player_win: true,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn can_second_player_king_reach_last_rank(state: &GameState) -> bool {
+    state.possible_moves.iter().any(|possible_move| {
+        matches!(
+            possible_move,
+            Move::RegularMove(_, to, tile) | Move::AttackMove(_, to, tile)
+                if *tile == Tile::SECOND_KING && to.is_last_rank()
+        )
+    })
+}
+
 impl Default for Game {
     fn default() -> Self {
         Self::new()
@@ -212,7 +304,7 @@ mod tests {
         let game = Game::new().unwrap_if_ongoing();
         let move_result = Ongoing { state: game };
 
-        assert_eq!(false, move_result.is_err());
+        assert!(!move_result.is_err());
     }
 
     #[test]
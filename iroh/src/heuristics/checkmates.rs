@@ -2,7 +2,7 @@ use crate::heuristics::cache::HeuristicsCache;
 use crate::heuristics::{Heuristic, HeuristicType};
 use crate::state::GameState;
 
-const VERY_BIG_NUMBER: i32 = 999999999;
+pub(crate) const VERY_BIG_NUMBER: i32 = 999999999;
 
 pub struct InCheckmateHeuristic {}
 
@@ -64,9 +64,9 @@ mod tests {
         state_ten_turns.turn_number = 10;
 
         let mut cache = HeuristicsCache::from(&mut state_one_turn);
-        let result_one_turn = InCheckmateHeuristic {}.evaluate(&mut state_one_turn, &cache);
+        let result_one_turn = InCheckmateHeuristic {}.evaluate(&state_one_turn, &cache);
         cache = HeuristicsCache::from(&mut state_ten_turns);
-        let result_ten_turns = InCheckmateHeuristic {}.evaluate(&mut state_ten_turns, &cache);
+        let result_ten_turns = InCheckmateHeuristic {}.evaluate(&state_ten_turns, &cache);
 
         assert_eq!(VERY_BIG_NUMBER - 10, result_one_turn);
         assert_eq!(VERY_BIG_NUMBER - 100, result_ten_turns);
@@ -80,9 +80,9 @@ mod tests {
         state_ten_turns.turn_number = 10;
 
         let mut cache = HeuristicsCache::from(&mut state_one_turn);
-        let result_one_turn = InCheckmateHeuristic {}.evaluate(&mut state_one_turn, &cache);
+        let result_one_turn = InCheckmateHeuristic {}.evaluate(&state_one_turn, &cache);
         cache = HeuristicsCache::from(&mut state_ten_turns);
-        let result_ten_turns = InCheckmateHeuristic {}.evaluate(&mut state_ten_turns, &cache);
+        let result_ten_turns = InCheckmateHeuristic {}.evaluate(&state_ten_turns, &cache);
 
         assert_eq!(-VERY_BIG_NUMBER + 10, result_one_turn);
         assert_eq!(-VERY_BIG_NUMBER + 100, result_ten_turns);
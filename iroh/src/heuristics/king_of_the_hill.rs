@@ -0,0 +1,78 @@
+use crate::heuristics::cache::HeuristicsCache;
+use crate::heuristics::checkmates::VERY_BIG_NUMBER;
+use crate::heuristics::{Heuristic, HeuristicType};
+use crate::state::check::find_king;
+use crate::state::variant::Variant;
+use crate::state::GameState;
+
+const VALUE_PER_TILE_FROM_CENTRE: i32 = 5;
+
+pub struct KingOfTheHillHeuristic {}
+
+impl Heuristic for KingOfTheHillHeuristic {
+    fn evaluate(&self, state: &GameState, _: &HeuristicsCache) -> i32 {
+        if state.variant != Variant::KingOfTheHill {
+            return 0;
+        }
+
+        let first_player_distance = distance_from_centre(true, state);
+        let second_player_distance = distance_from_centre(false, state);
+        match (first_player_distance, second_player_distance) {
+            (Some(0), _) => VERY_BIG_NUMBER - (state.turn_number * 10) as i32,
+            (_, Some(0)) => -VERY_BIG_NUMBER + (state.turn_number * 10) as i32,
+            (Some(first), Some(second)) => {
+                (second as i32 - first as i32) * VALUE_PER_TILE_FROM_CENTRE
+            }
+            _ => 0,
+        }
+    }
+
+    fn get_type(&self) -> HeuristicType {
+        HeuristicType::KingOfTheHill
+    }
+}
+
+fn distance_from_centre(is_first_player: bool, state: &GameState) -> Option<u8> {
+    find_king(is_first_player, state).map(|king| king.1.distance_from_centre())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_standard_game_returns_0() {
+        let mut state = GameState::from_fen("8/8/8/3k4/8/8/8/4K3 w - - 0 1");
+
+        let cache = HeuristicsCache::from(&mut state);
+        let result = KingOfTheHillHeuristic {}.evaluate(&state, &cache);
+
+        assert_eq!(0, result);
+    }
+
+    #[test]
+    fn given_first_player_king_closer_to_centre_returns_positive_value() {
+        let mut state = GameState::from_fen_with_variant(
+            "7k/8/8/8/8/4K3/8/8 w - - 0 1",
+            Variant::KingOfTheHill,
+        );
+
+        let cache = HeuristicsCache::from(&mut state);
+        let result = KingOfTheHillHeuristic {}.evaluate(&state, &cache);
+
+        assert_eq!(2 * VALUE_PER_TILE_FROM_CENTRE, result);
+    }
+
+    #[test]
+    fn given_second_player_king_on_hill_return_extremely_big_negative_value() {
+        let mut state = GameState::from_fen_with_variant(
+            "8/8/8/3k4/8/8/8/4K3 w - - 0 1",
+            Variant::KingOfTheHill,
+        );
+
+        let cache = HeuristicsCache::from(&mut state);
+        let result = KingOfTheHillHeuristic {}.evaluate(&state, &cache);
+
+        assert_eq!(-VERY_BIG_NUMBER + 10, result);
+    }
+}
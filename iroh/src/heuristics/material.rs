@@ -32,7 +32,7 @@ impl Heuristic for MaterialHeuristic {
     }
 }
 
-fn material_for(tile: Tile) -> i32 {
+pub(crate) fn material_for(tile: Tile) -> i32 {
     match tile {
         Tile::FIRST_PAWN | Tile::SECOND_PAWN => 1,
         Tile::FIRST_BISHOP | Tile::SECOND_BISHOP => 3,
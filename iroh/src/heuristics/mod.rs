@@ -2,16 +2,21 @@ pub mod attacks;
 mod cache;
 pub mod checkmates;
 pub mod checks;
+pub mod king_of_the_hill;
 pub mod material;
 pub mod mobility;
+pub mod three_check;
 pub mod weightings;
 
 use crate::heuristics::attacks::AttacksHeuristic;
 use crate::heuristics::checkmates::InCheckmateHeuristic;
 use crate::heuristics::checks::InCheckHeuristic;
-use crate::heuristics::material::MaterialHeuristic;
+use crate::heuristics::king_of_the_hill::KingOfTheHillHeuristic;
+use crate::heuristics::material::{material_for, MaterialHeuristic};
 use crate::heuristics::mobility::MobilityHeuristic;
+use crate::heuristics::three_check::ThreeCheckHeuristic;
 use crate::heuristics::weightings::Weightings;
+use crate::state::tile::Tile;
 use crate::state::GameState;
 use cache::HeuristicsCache;
 
@@ -22,9 +27,12 @@ pub enum HeuristicType {
     Attacks,
     InCheck,
     CheckMate,
+    ThreeCheck,
+    KingOfTheHill,
 }
 
-pub trait Heuristic {
+///Heuristics are shared by every search thread
+pub trait Heuristic: Send + Sync {
     fn evaluate(&self, state: &GameState, heuristics_cache: &HeuristicsCache) -> i32;
     fn get_type(&self) -> HeuristicType;
 }
@@ -42,6 +50,8 @@ impl Default for Heuristics {
             Box::new(AttacksHeuristic {}),
             Box::new(InCheckHeuristic {}),
             Box::new(InCheckmateHeuristic {}),
+            Box::new(ThreeCheckHeuristic {}),
+            Box::new(KingOfTheHillHeuristic {}),
         ];
         Heuristics {
             heuristics,
@@ -76,17 +86,23 @@ impl Heuristics {
             let heuristic_value = heuristic.evaluate(state, &heuristics_cache);
             let heuristic_weight = self.weightings.get(heuristic.get_type()).unwrap_or(1.0);
             let weighted_value = (heuristic_value as f32 * heuristic_weight).round() as i32;
-            #[cfg(debug_assertions)]
-            {
-                let heuristic_type = heuristic.get_type();
-                println!("Valued {heuristic_type:?} at {weighted_value}");
-            }
-
             result += weighted_value;
         }
         result
     }
 
+    ///What a piece is worth to the material heuristic, after weighting
+    pub(crate) fn weighted_material(&self, tile: Tile) -> i32 {
+        let weight = self.weightings.get(HeuristicType::Material).unwrap_or(1.0);
+        (material_for(tile) as f32 * weight).round() as i32
+    }
+
+    ///What a pawn is worth after weighting, at least 1 so that values can be divided by it even
+    ///when material is weighted down to nothing
+    pub(crate) fn pawn_value(&self) -> i32 {
+        self.weighted_material(Tile::FIRST_PAWN).max(1)
+    }
+
     pub fn push<T: Heuristic + 'static>(&mut self, heuristic: T) {
         self.heuristics.push(Box::new(heuristic));
     }
@@ -0,0 +1,69 @@
+use crate::heuristics::cache::HeuristicsCache;
+use crate::heuristics::checkmates::VERY_BIG_NUMBER;
+use crate::heuristics::{Heuristic, HeuristicType};
+use crate::state::variant::Variant;
+use crate::state::GameState;
+
+const VALUE_PER_CHECK: i32 = 25;
+
+pub struct ThreeCheckHeuristic {}
+
+impl Heuristic for ThreeCheckHeuristic {
+    fn evaluate(&self, state: &GameState, _: &HeuristicsCache) -> i32 {
+        if state.variant != Variant::ThreeCheck {
+            0
+        } else if state.first_player_checks_given >= 3 {
+            VERY_BIG_NUMBER - (state.turn_number * 10) as i32
+        } else if state.second_player_checks_given >= 3 {
+            -VERY_BIG_NUMBER + (state.turn_number * 10) as i32
+        } else {
+            (state.first_player_checks_given as i32 - state.second_player_checks_given as i32)
+                * VALUE_PER_CHECK
+        }
+    }
+
+    fn get_type(&self) -> HeuristicType {
+        HeuristicType::ThreeCheck
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_standard_game_returns_0() {
+        let mut state = GameState::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1 +2+0");
+
+        let cache = HeuristicsCache::from(&mut state);
+        let result = ThreeCheckHeuristic {}.evaluate(&state, &cache);
+
+        assert_eq!(0, result);
+    }
+
+    #[test]
+    fn given_more_checks_given_by_first_player_returns_positive_value() {
+        let mut state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3 w - - 0 1 +2+1",
+            Variant::ThreeCheck,
+        );
+
+        let cache = HeuristicsCache::from(&mut state);
+        let result = ThreeCheckHeuristic {}.evaluate(&state, &cache);
+
+        assert_eq!(VALUE_PER_CHECK, result);
+    }
+
+    #[test]
+    fn given_second_player_gave_three_checks_return_extremely_big_negative_value() {
+        let mut state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3 w - - 0 1 +0+3",
+            Variant::ThreeCheck,
+        );
+
+        let cache = HeuristicsCache::from(&mut state);
+        let result = ThreeCheckHeuristic {}.evaluate(&state, &cache);
+
+        assert_eq!(-VERY_BIG_NUMBER + 10, result);
+    }
+}
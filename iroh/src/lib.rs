@@ -1,4 +1,3 @@
-mod error;
 pub mod game;
 pub mod heuristics;
 pub mod moves;
@@ -0,0 +1,101 @@
+use crate::moves::Move;
+use crate::state::coordinates::Coordinate;
+use crate::state::tile::Tile;
+use crate::state::GameState;
+
+pub fn generate_drop_moves(
+    available_moves: &mut Vec<Move>,
+    game_state: &GameState,
+    is_for_first_player: bool,
+) {
+    let mut pocket = game_state.captured_pieces.pocket(is_for_first_player);
+    pocket.sort_by_key(|tile| **tile);
+    pocket.dedup();
+
+    for tile in pocket {
+        let is_pawn = tile == Tile::FIRST_PAWN || tile == Tile::SECOND_PAWN;
+        for rank in 0_u8..8 {
+            for file in 0_u8..8 {
+                let coordinate = Coordinate::from_u8_no_bounds_check(file + rank * 16);
+                let is_empty = !game_state.board[coordinate].is_occupied();
+                let is_back_rank = coordinate.is_first_rank() || coordinate.is_last_rank();
+                if is_empty && !(is_pawn && is_back_rank) {
+                    available_moves.push(Move::Drop(tile, coordinate));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::variant::Variant;
+
+    #[test]
+    fn given_empty_pocket_generates_no_drops() {
+        let state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+        let mut available_moves = vec![];
+
+        generate_drop_moves(&mut available_moves, &state, true);
+
+        assert!(available_moves.is_empty());
+    }
+
+    #[test]
+    fn piece_can_be_dropped_on_every_empty_tile() {
+        let state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[N] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+        let mut available_moves = vec![];
+
+        generate_drop_moves(&mut available_moves, &state, true);
+
+        assert_eq!(62, available_moves.len());
+    }
+
+    #[test]
+    fn pawn_cannot_be_dropped_on_first_or_last_rank() {
+        let state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[p] b - - 0 1",
+            Variant::Crazyhouse,
+        );
+        let mut available_moves = vec![];
+
+        generate_drop_moves(&mut available_moves, &state, false);
+
+        assert_eq!(48, available_moves.len());
+        assert!(!available_moves.contains(&Move::Drop(Tile::SECOND_PAWN, Coordinate::A1)));
+        assert!(!available_moves.contains(&Move::Drop(Tile::SECOND_PAWN, Coordinate::A8)));
+    }
+
+    #[test]
+    fn duplicate_pieces_in_pocket_generate_one_set_of_drops() {
+        let state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[RR] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+        let mut available_moves = vec![];
+
+        generate_drop_moves(&mut available_moves, &state, true);
+
+        assert_eq!(62, available_moves.len());
+    }
+
+    #[test]
+    fn only_drops_own_pieces() {
+        let state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[q] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+        let mut available_moves = vec![];
+
+        generate_drop_moves(&mut available_moves, &state, true);
+
+        assert!(available_moves.is_empty());
+    }
+}
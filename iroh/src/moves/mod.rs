@@ -1,6 +1,7 @@
 use crate::serialisers::san::{
-    generate_attack_san, generate_castling_san, generate_pawn_attack_san,
-    generate_pawn_promotion_san, generate_pawn_san, generate_san,
+    generate_attack_san, generate_castling_san, generate_drop_san,
+    generate_pawn_attack_promotion_san, generate_pawn_attack_san, generate_pawn_promotion_san,
+    generate_pawn_san, generate_san,
 };
 use crate::state::coordinates::Coordinate;
 use crate::state::tile::Tile;
@@ -8,6 +9,7 @@ use std::fmt::{Display, Formatter};
 
 mod castling_moves;
 pub(crate) mod coordinate_transformers;
+mod drop_moves;
 mod dynamic_moves;
 pub mod move_generation;
 mod pawn_moves;
@@ -21,8 +23,10 @@ pub enum Move {
     PawnMove(Coordinate, Coordinate),
     PawnAttackMove(Coordinate, Coordinate),
     PawnPromotion(Coordinate, Tile),
+    PawnAttackPromotion(Coordinate, Coordinate, Tile),
     Castle(bool),
-    EnPassant(Coordinate, Coordinate)
+    EnPassant(Coordinate, Coordinate),
+    Drop(Tile, Coordinate),
 }
 
 impl Display for Move {
@@ -41,10 +45,34 @@ impl Move {
             }
             Move::PawnAttackMove(from, to) => generate_pawn_attack_san(from, to),
             Move::PawnPromotion(file, piece) => generate_pawn_promotion_san(file, piece),
+            Move::PawnAttackPromotion(from, to, piece) => {
+                generate_pawn_attack_promotion_san(from, to, piece)
+            }
             Move::Castle(is_kingside) => generate_castling_san(is_kingside),
-            Move::EnPassant(from, to) => generate_pawn_attack_san(from, to)
+            Move::EnPassant(from, to) => generate_pawn_attack_san(from, to),
+            Move::Drop(piece, to) => generate_drop_san(piece, to),
         }
     }
+
+    pub fn is_capture(&self) -> bool {
+        matches!(
+            self,
+            Move::AttackMove(..)
+                | Move::PawnAttackMove(..)
+                | Move::PawnAttackPromotion(..)
+                | Move::EnPassant(..)
+        )
+    }
+
+    ///No position from before this move can be repeated after it
+    pub fn is_irreversible(&self) -> bool {
+        self.resets_halfmove_clock() || matches!(self, Move::Castle(_) | Move::Drop(..))
+    }
+
+    ///Pawn moves and captures restart the count towards the fifty-move rule
+    pub fn resets_halfmove_clock(&self) -> bool {
+        self.is_capture() || matches!(self, Move::PawnMove(..) | Move::PawnPromotion(..))
+    }
 }
 
 #[cfg(test)]
@@ -77,4 +105,60 @@ mod tests {
 
         assert_eq!("bxc6", result);
     }
+
+    #[test]
+    fn given_pawn_attack_promotion_should_generate_san() {
+        let test_move =
+            Move::PawnAttackPromotion(Coordinate::B7, Coordinate::C8, Tile::FIRST_KNIGHT);
+
+        let result = test_move.generate_san();
+
+        assert_eq!("bxc8=N", result);
+    }
+
+    #[test]
+    fn given_drop_move_should_generate_san() {
+        let test_move = Move::Drop(Tile::SECOND_KNIGHT, Coordinate::F3);
+
+        let result = test_move.generate_san();
+
+        assert_eq!("N@f3", result);
+    }
+
+    #[test]
+    fn attacks_and_en_passant_are_captures() {
+        let attack = Move::AttackMove(Coordinate::B2, Coordinate::C4, Tile::FIRST_KNIGHT);
+        let pawn_attack = Move::PawnAttackMove(Coordinate::B5, Coordinate::C6);
+        let en_passant = Move::EnPassant(Coordinate::B5, Coordinate::C6);
+        let quiet_move = Move::PawnMove(Coordinate::C3, Coordinate::C4);
+
+        assert!(attack.is_capture());
+        assert!(pawn_attack.is_capture());
+        assert!(en_passant.is_capture());
+        assert!(!quiet_move.is_capture());
+    }
+
+    #[test]
+    fn pawn_moves_captures_and_castling_are_irreversible() {
+        let pawn_move = Move::PawnMove(Coordinate::C3, Coordinate::C4);
+        let capture = Move::AttackMove(Coordinate::B2, Coordinate::C4, Tile::FIRST_KNIGHT);
+        let castle = Move::Castle(true);
+        let knight_move = Move::RegularMove(Coordinate::B2, Coordinate::C4, Tile::FIRST_KNIGHT);
+
+        assert!(pawn_move.is_irreversible());
+        assert!(capture.is_irreversible());
+        assert!(castle.is_irreversible());
+        assert!(!knight_move.is_irreversible());
+    }
+
+    #[test]
+    fn only_pawn_moves_and_captures_reset_halfmove_clock() {
+        let pawn_move = Move::PawnMove(Coordinate::C3, Coordinate::C4);
+        let capture = Move::AttackMove(Coordinate::B2, Coordinate::C4, Tile::FIRST_KNIGHT);
+        let castle = Move::Castle(true);
+
+        assert!(pawn_move.resets_halfmove_clock());
+        assert!(capture.resets_halfmove_clock());
+        assert!(!castle.resets_halfmove_clock());
+    }
 }
@@ -1,7 +1,9 @@
 use crate::moves::castling_moves::generate_castling_moves;
+use crate::moves::drop_moves::generate_drop_moves;
 use crate::moves::resolve_move::{perform_move_for, undo_move};
 use crate::moves::{dynamic_moves, pawn_moves, static_moves, Move};
 use crate::state::tile::Tile;
+use crate::state::variant::Variant;
 use crate::state::GameState;
 
 pub fn generate_moves(game_state: &mut GameState, is_for_first_player: bool) -> Vec<Move> {
@@ -54,13 +56,33 @@ pub fn generate_moves(game_state: &mut GameState, is_for_first_player: bool) ->
         }
     }
 
-    generate_castling_moves(&mut available_moves, game_state, is_for_first_player);
+    if game_state.variant != Variant::Antichess {
+        generate_castling_moves(&mut available_moves, game_state, is_for_first_player);
+    }
+    if game_state.variant == Variant::Crazyhouse {
+        generate_drop_moves(&mut available_moves, game_state, is_for_first_player);
+    }
     available_moves =
         remove_moves_that_result_in_check(available_moves, game_state, is_for_first_player);
+    if game_state.variant == Variant::Antichess {
+        available_moves = remove_non_captures_if_capture_available(available_moves);
+    }
 
     available_moves
 }
 
+///Captures are compulsory in Antichess
+fn remove_non_captures_if_capture_available(available_moves: Vec<Move>) -> Vec<Move> {
+    if available_moves.iter().any(Move::is_capture) {
+        available_moves
+            .into_iter()
+            .filter(Move::is_capture)
+            .collect()
+    } else {
+        available_moves
+    }
+}
+
 fn remove_moves_that_result_in_check(
     available_moves: Vec<Move>,
     game_state: &mut GameState,
@@ -70,23 +92,40 @@ fn remove_moves_that_result_in_check(
         .into_iter()
         .filter(|requested_move| {
             let memento = perform_move_for(requested_move, game_state, is_for_first_player);
-            let is_check = game_state.is_check(is_for_first_player);
+            let is_legal = is_legal_position_after_move(game_state, is_for_first_player);
             undo_move(memento, game_state);
-            !is_check
+            is_legal
         })
         .collect()
 }
 
+fn is_legal_position_after_move(game_state: &GameState, is_for_first_player: bool) -> bool {
+    match game_state.variant {
+        //Exploding the enemy king wins immediately, even if it leaves our own king in check
+        Variant::Atomic => {
+            game_state.has_king(is_for_first_player)
+                && (!game_state.has_king(!is_for_first_player)
+                    || !game_state.is_check(is_for_first_player))
+        }
+        //Neither king may be put in check in Racing Kings
+        Variant::RacingKings => {
+            !game_state.is_check(is_for_first_player) && !game_state.is_check(!is_for_first_player)
+        }
+        _ => !game_state.is_check(is_for_first_player),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::coordinates::Coordinate;
 
     #[test]
     fn generates_moves_for_either_player() {
         let mut state = GameState::from_fen("8/2nk4/3q4/8/2P1P3/2K5/8/8 w - - 0 1");
 
-        let n_moves_for_first_player = generate_moves(&mut state, true).iter().count();
-        let n_moves_for_second_player = generate_moves(&mut state, false).iter().count();
+        let n_moves_for_first_player = generate_moves(&mut state, true).len();
+        let n_moves_for_second_player = generate_moves(&mut state, false).len();
 
         assert_eq!(5, n_moves_for_first_player);
         assert_eq!(33, n_moves_for_second_player);
@@ -109,4 +148,83 @@ mod tests {
         assert_eq!(0, n_castle_moves_for_first_player);
         assert_eq!(1, n_castle_moves_for_second_player);
     }
+
+    #[test]
+    fn given_horde_pawn_on_first_rank_can_move_two_tiles() {
+        let mut state =
+            GameState::from_fen_with_variant("4k3/8/8/8/8/8/8/P7 w - - 0 1", Variant::Horde);
+
+        let result = generate_moves(&mut state, true);
+
+        assert_eq!(
+            vec![
+                Move::PawnMove(Coordinate::A1, Coordinate::A2),
+                Move::PawnMove(Coordinate::A1, Coordinate::A3)
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn given_racing_kings_cannot_give_check() {
+        let mut state =
+            GameState::from_fen_with_variant("8/8/8/k7/8/8/8/1R5K w - - 0 1", Variant::RacingKings);
+
+        let result = generate_moves(&mut state, true);
+
+        assert!(!result.contains(&Move::RegularMove(
+            Coordinate::B1,
+            Coordinate::A1,
+            Tile::FIRST_ROOK
+        )));
+        assert!(!result.contains(&Move::RegularMove(
+            Coordinate::B1,
+            Coordinate::B5,
+            Tile::FIRST_ROOK
+        )));
+        assert!(result.contains(&Move::RegularMove(
+            Coordinate::B1,
+            Coordinate::B2,
+            Tile::FIRST_ROOK
+        )));
+    }
+
+    #[test]
+    fn given_antichess_capture_is_compulsory() {
+        let mut state = GameState::from_fen_with_variant(
+            "4k3/8/8/3p4/4P3/8/8/RN2K3 w - - 0 1",
+            Variant::Antichess,
+        );
+
+        let result = generate_moves(&mut state, true);
+
+        assert_eq!(
+            vec![Move::PawnAttackMove(Coordinate::E4, Coordinate::D5)],
+            result
+        );
+    }
+
+    #[test]
+    fn given_antichess_pawn_can_promote_to_king() {
+        let mut state =
+            GameState::from_fen_with_variant("8/P7/8/8/8/8/8/7k w - - 0 1", Variant::Antichess);
+
+        let result = generate_moves(&mut state, true);
+
+        assert!(result.contains(&Move::PawnPromotion(Coordinate::A8, Tile::FIRST_KING)));
+    }
+
+    #[test]
+    fn given_antichess_king_may_move_into_attack() {
+        let mut state =
+            GameState::from_fen_with_variant("4k3/8/8/8/8/8/r7/4K3 w - - 0 1", Variant::Antichess);
+
+        let result = generate_moves(&mut state, true);
+
+        assert!(result.contains(&Move::RegularMove(
+            Coordinate::E1,
+            Coordinate::E2,
+            Tile::FIRST_KING
+        )));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::moves::Move;
 use crate::state::coordinates::Coordinate;
 use crate::state::tile::Tile;
+use crate::state::variant::Variant;
 use crate::state::GameState;
 
 pub fn generate_pawn_moves(
@@ -23,13 +24,19 @@ pub fn generate_pawn_moves(
             ahead_coordinate.is_first_rank()
         };
         let is_on_starting_rank = if is_for_first_player {
-            pawn.1.is_rank_2()
+            //Horde pawns on the first rank may also move two tiles
+            pawn.1.is_rank_2() || (game_state.variant == Variant::Horde && pawn.1.is_first_rank())
         } else {
             pawn.1.is_rank_7()
         };
 
         if ahead_rank_is_last_rank {
-            generate_promotion_moves(available_moves, ahead_coordinate, is_for_first_player);
+            generate_promotion_moves(
+                game_state,
+                available_moves,
+                ahead_coordinate,
+                is_for_first_player,
+            );
         } else {
             available_moves.push(Move::PawnMove(pawn.1, ahead_coordinate));
 
@@ -110,48 +117,52 @@ fn generate_attack_move(
     if let Some(target) = target {
         let tile = game_state.board[target];
         if tile.is_occupied() && tile.is_owned_by_first_player() != is_for_first_player {
-            available_moves.push(Move::PawnAttackMove(start, target))
+            if target.is_first_rank() || target.is_last_rank() {
+                for promote_to in promotion_pieces(game_state, is_for_first_player) {
+                    available_moves.push(Move::PawnAttackPromotion(start, target, promote_to));
+                }
+            } else {
+                available_moves.push(Move::PawnAttackMove(start, target))
+            }
         }
     }
 }
 
 fn generate_promotion_moves(
+    game_state: &GameState,
     available_moves: &mut Vec<Move>,
     to: Coordinate,
     is_for_first_player: bool,
 ) {
-    available_moves.push(Move::PawnPromotion(
-        to,
-        if is_for_first_player {
-            Tile::FIRST_QUEEN
-        } else {
-            Tile::SECOND_QUEEN
-        },
-    ));
-    available_moves.push(Move::PawnPromotion(
-        to,
-        if is_for_first_player {
-            Tile::FIRST_ROOK
-        } else {
-            Tile::SECOND_ROOK
-        },
-    ));
-    available_moves.push(Move::PawnPromotion(
-        to,
-        if is_for_first_player {
-            Tile::FIRST_BISHOP
-        } else {
-            Tile::SECOND_BISHOP
-        },
-    ));
-    available_moves.push(Move::PawnPromotion(
-        to,
-        if is_for_first_player {
-            Tile::FIRST_KNIGHT
+    for tile in promotion_pieces(game_state, is_for_first_player) {
+        available_moves.push(Move::PawnPromotion(to, tile));
+    }
+}
+
+fn promotion_pieces(game_state: &GameState, is_for_first_player: bool) -> Vec<Tile> {
+    let mut pieces = if is_for_first_player {
+        vec![
+            Tile::FIRST_QUEEN,
+            Tile::FIRST_ROOK,
+            Tile::FIRST_BISHOP,
+            Tile::FIRST_KNIGHT,
+        ]
+    } else {
+        vec![
+            Tile::SECOND_QUEEN,
+            Tile::SECOND_ROOK,
+            Tile::SECOND_BISHOP,
+            Tile::SECOND_KNIGHT,
+        ]
+    };
+    if game_state.variant == Variant::Antichess {
+        pieces.push(if is_for_first_player {
+            Tile::FIRST_KING
         } else {
-            Tile::SECOND_KNIGHT
-        },
-    ));
+            Tile::SECOND_KING
+        });
+    }
+    pieces
 }
 
 fn generate_en_passant(
@@ -160,21 +171,15 @@ fn generate_en_passant(
     pawn: (Tile, Coordinate),
     is_for_first_player: bool,
 ) {
-    if is_for_first_player && pawn.1.is_rank_5() {
-        let move_target = pawn.1.north_east();
-        let attack_target = pawn.1.east();
-        if let (Some(move_target), Some(attack_target)) = (move_target, attack_target) {
-            if game_state.sans.last() == Some(&format!("{}",attack_target)) {
-                available_moves.push(Move::EnPassant(pawn.1, move_target))
-            }
-        }
-    } else if !is_for_first_player && pawn.1.is_rank_4() {
-        let move_target = pawn.1.south_west();
-        let attack_target = pawn.1.west();
-        if let (Some(move_target), Some(attack_target)) = (move_target, attack_target) {
-            if game_state.board[attack_target] == Tile::FIRST_PAWN {
-                available_moves.push(Move::EnPassant(pawn.1, move_target))
-            }
-        }
+    let Some(target) = game_state.en_passant_target else {
+        return;
+    };
+    let attacks = if is_for_first_player {
+        [pawn.1.north_east(), pawn.1.north_west()]
+    } else {
+        [pawn.1.south_east(), pawn.1.south_west()]
+    };
+    if attacks.contains(&Some(target)) {
+        available_moves.push(Move::EnPassant(pawn.1, target))
     }
 }
@@ -1,6 +1,8 @@
+use crate::moves::coordinate_transformers::KING_STATIC_TRANSFORMERS;
 use crate::moves::Move;
 use crate::state::coordinates::Coordinate;
 use crate::state::tile::Tile;
+use crate::state::variant::Variant;
 use crate::state::GameState;
 
 pub struct ResolvedMoveMemento<'a> {
@@ -8,6 +10,7 @@ pub struct ResolvedMoveMemento<'a> {
     captured_piece: Tile,
     is_first_player: bool,
     castling_state: CastlingStateMemento,
+    exploded_pieces: Vec<(Tile, Coordinate)>,
 }
 
 impl<'a> ResolvedMoveMemento<'a> {
@@ -22,6 +25,7 @@ impl<'a> ResolvedMoveMemento<'a> {
             captured_piece,
             is_first_player,
             castling_state,
+            exploded_pieces: vec![],
         }
     }
 }
@@ -53,25 +57,98 @@ impl CastlingStateMemento {
 
 pub fn resolve_move(requested_move: &Move, game_state: &mut GameState) {
     let is_first_player_turn = game_state.is_first_player_turn;
+    let is_promoted_piece_captured = is_promoted_piece_captured(requested_move, game_state);
     let memento = perform_move_for(requested_move, game_state, is_first_player_turn);
     if memento.captured_piece != Tile::EMPTY {
+        //In Crazyhouse a promoted piece goes back to being a pawn when it is captured
+        let captured_piece = match (is_promoted_piece_captured, is_first_player_turn) {
+            (true, true) => Tile::SECOND_PAWN,
+            (true, false) => Tile::FIRST_PAWN,
+            (false, _) => memento.captured_piece,
+        };
         if is_first_player_turn {
             game_state
                 .captured_pieces
-                .captured_second_player(memento.captured_piece, game_state.turn_number);
+                .captured_second_player(captured_piece, game_state.turn_number);
         } else {
             game_state
                 .captured_pieces
-                .captured_first_player(memento.captured_piece, game_state.turn_number);
+                .captured_first_player(captured_piece, game_state.turn_number);
+        }
+    }
+    if let Move::Drop(tile, _) = requested_move {
+        game_state.captured_pieces.remove_from_pocket(*tile);
+    }
+    if game_state.variant == Variant::Crazyhouse {
+        update_promoted_pieces(requested_move, game_state);
+    }
+    if game_state.variant == Variant::ThreeCheck && game_state.is_check(!is_first_player_turn) {
+        if is_first_player_turn {
+            game_state.first_player_checks_given += 1;
+        } else {
+            game_state.second_player_checks_given += 1;
         }
     }
     game_state.next_turn();
 }
 
+fn is_promoted_piece_captured(requested_move: &Move, game_state: &GameState) -> bool {
+    match requested_move {
+        Move::AttackMove(_, to, _)
+        | Move::PawnAttackMove(_, to)
+        | Move::PawnAttackPromotion(_, to, _) => game_state.promoted_pieces.contains(to),
+        _ => false,
+    }
+}
+
+fn update_promoted_pieces(requested_move: &Move, game_state: &mut GameState) {
+    match *requested_move {
+        Move::RegularMove(from, to, _) | Move::AttackMove(from, to, _) => {
+            game_state
+                .promoted_pieces
+                .retain(|coordinate| *coordinate != to);
+            if let Some(coordinate) = game_state
+                .promoted_pieces
+                .iter_mut()
+                .find(|coordinate| **coordinate == from)
+            {
+                *coordinate = to;
+            }
+        }
+        Move::PawnAttackMove(_, to) => {
+            game_state
+                .promoted_pieces
+                .retain(|coordinate| *coordinate != to);
+        }
+        Move::PawnPromotion(to, _) => game_state.promoted_pieces.push(to),
+        Move::PawnAttackPromotion(_, to, _) => {
+            game_state
+                .promoted_pieces
+                .retain(|coordinate| *coordinate != to);
+            game_state.promoted_pieces.push(to);
+        }
+        _ => {}
+    }
+}
+
 pub fn perform_move_for<'a>(
     requested_move: &'a Move,
     game_state: &mut GameState,
     is_first_player: bool,
+) -> ResolvedMoveMemento<'a> {
+    let mut memento = perform_move_without_explosion(requested_move, game_state, is_first_player);
+    if game_state.variant == Variant::Atomic && memento.captured_piece != Tile::EMPTY {
+        if let Some(epicentre) = capture_target(requested_move) {
+            memento.exploded_pieces = explode(game_state, epicentre);
+        }
+    }
+    memento
+}
+
+fn perform_move_without_explosion<'a>(
+    requested_move: &'a Move,
+    game_state: &mut GameState,
+    is_first_player: bool,
 ) -> ResolvedMoveMemento<'a> {
     let castle_state = CastlingStateMemento::new(game_state);
 
@@ -115,6 +192,17 @@ pub fn perform_move_for<'a>(
             game_state.board[target] = *tile;
             ResolvedMoveMemento::new(requested_move, Tile::EMPTY, is_first_player, castle_state)
         }
+        Move::PawnAttackPromotion(from, to, tile) => {
+            let target_tile = game_state.board[to];
+            assert!(
+                target_tile.is_occupied(),
+                "Illegal move, no target to attack"
+            );
+
+            game_state.board[from] = Tile::EMPTY;
+            game_state.board[to] = *tile;
+            ResolvedMoveMemento::new(requested_move, target_tile, is_first_player, castle_state)
+        }
         Move::Castle(is_kingside) => {
             match (is_first_player, is_kingside) {
                 (true, true) => {
@@ -138,10 +226,55 @@ pub fn perform_move_for<'a>(
         },
         Move::EnPassant(from,to) => {
             move_piece(game_state, from, to);
-            game_state.board[to.south().unwrap()] = Tile::EMPTY;
-            ResolvedMoveMemento::new(requested_move, Tile::SECOND_PAWN, is_first_player, castle_state)
+            let captured = if is_first_player {
+                to.south()
+            } else {
+                to.north()
+            }
+            .expect("En passant never captures off the board");
+            let captured_piece = game_state.board[captured];
+            game_state.board[captured] = Tile::EMPTY;
+            ResolvedMoveMemento::new(
+                requested_move,
+                captured_piece,
+                is_first_player,
+                castle_state,
+            )
+        }
+        Move::Drop(tile, to) => {
+            game_state.board[to] = *tile;
+            ResolvedMoveMemento::new(requested_move, Tile::EMPTY, is_first_player, castle_state)
+        }
+    }
+}
+
+fn capture_target(requested_move: &Move) -> Option<Coordinate> {
+    match *requested_move {
+        Move::AttackMove(_, to, _)
+        | Move::PawnAttackMove(_, to)
+        | Move::PawnAttackPromotion(_, to, _)
+        | Move::EnPassant(_, to) => Some(to),
+        _ => None,
+    }
+}
+
+///In Atomic chess a capture destroys the capturing piece and every non-pawn piece next to it
+fn explode(game_state: &mut GameState, epicentre: Coordinate) -> Vec<(Tile, Coordinate)> {
+    let mut exploded_pieces = vec![(game_state.board[epicentre], epicentre)];
+    game_state.board[epicentre] = Tile::EMPTY;
+
+    for transformer in KING_STATIC_TRANSFORMERS {
+        if let Some(coordinate) = transformer(epicentre) {
+            let tile = game_state.board[coordinate];
+            if tile.is_occupied() && tile != Tile::FIRST_PAWN && tile != Tile::SECOND_PAWN {
+                exploded_pieces.push((tile, coordinate));
+                game_state.board[coordinate] = Tile::EMPTY;
+                update_castling_state(game_state, &coordinate, tile);
+            }
         }
     }
+
+    exploded_pieces
 }
 
 pub fn undo_move(memento: ResolvedMoveMemento, game_state: &mut GameState) {
@@ -150,8 +283,13 @@ pub fn undo_move(memento: ResolvedMoveMemento, game_state: &mut GameState) {
         captured_piece,
         is_first_player,
         castling_state,
+        exploded_pieces,
     } = memento;
 
+    for (tile, coordinate) in exploded_pieces {
+        game_state.board[coordinate] = tile;
+    }
+
     match last_move {
         Move::RegularMove(from, to, _) => {
             move_piece(game_state, to, from);
@@ -180,6 +318,14 @@ pub fn undo_move(memento: ResolvedMoveMemento, game_state: &mut GameState) {
                 Tile::SECOND_PAWN
             };
         }
+        Move::PawnAttackPromotion(from, to, _) => {
+            game_state.board[to] = captured_piece;
+            game_state.board[from] = if is_first_player {
+                Tile::FIRST_PAWN
+            } else {
+                Tile::SECOND_PAWN
+            };
+        }
         Move::Castle(is_kingside) => match (is_first_player, is_kingside) {
             (true, true) => {
                 move_piece(game_state, &Coordinate::G1, &Coordinate::E1);
@@ -201,11 +347,14 @@ pub fn undo_move(memento: ResolvedMoveMemento, game_state: &mut GameState) {
         Move::EnPassant(from,to) => {
             move_piece(game_state, to, from);
             if is_first_player {
-                game_state.board[to.south().unwrap()] = memento.captured_piece;
+                game_state.board[to.south().unwrap()] = captured_piece;
             } else {
-                game_state.board[to.north().unwrap()] = memento.captured_piece;
+                game_state.board[to.north().unwrap()] = captured_piece;
             }
         }
+        Move::Drop(_, to) => {
+            game_state.board[to] = Tile::EMPTY;
+        }
     }
     castling_state.apply(game_state);
 }
@@ -320,6 +469,20 @@ mod tests {
         assert_eq!("6k1/2P5/8/8/1K6/8/8/8 w - - 0 1", state.generate_fen());
     }
 
+    #[test]
+    fn undo_pawn_attack_promotion() {
+        let mut state = GameState::from_fen("3r2k1/2P5/8/8/1K6/8/8/8 w - - 0 1");
+        let requested_move = PawnAttackPromotion(Coordinate::C7, Coordinate::D8, Tile::FIRST_QUEEN);
+
+        let memento = perform_move_for(&requested_move, &mut state, true);
+
+        assert_eq!("3Q2k1/8/8/8/1K6/8/8/8 w - - 0 1", state.generate_fen());
+
+        undo_move(memento, &mut state);
+
+        assert_eq!("3r2k1/2P5/8/8/1K6/8/8/8 w - - 0 1", state.generate_fen());
+    }
+
     #[test]
     fn undo_castling() {
         let mut state = GameState::from_fen("4k3/8/8/8/8/8/8/4K2R w K - 0 1");
@@ -349,4 +512,119 @@ mod tests {
 
         assert_eq!("3k4/8/8/1Pp5/8/8/8/3K4 w - - 0 1", state.generate_fen());
     }
+
+    #[test]
+    fn undo_second_player_en_passant() {
+        let mut state = GameState::from_fen("3k4/8/8/8/2p5/8/1P1Q4/3K4 w - - 0 1");
+        state = state.make_move_san("b4").unwrap();
+        let requested_move = EnPassant(Coordinate::C4, Coordinate::B3);
+
+        let memento = perform_move_for(&requested_move, &mut state, false);
+
+        assert_eq!("3k4/8/8/8/8/1p6/3Q4/3K4 b - - 0 1", state.generate_fen());
+
+        undo_move(memento, &mut state);
+
+        assert_eq!("3k4/8/8/8/1Pp5/8/3Q4/3K4 b - - 0 1", state.generate_fen());
+    }
+
+    #[test]
+    fn undo_drop() {
+        let mut state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[N] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+        let requested_move = Drop(Tile::FIRST_KNIGHT, Coordinate::F3);
+
+        let memento = perform_move_for(&requested_move, &mut state, true);
+
+        assert_eq!("4k3/8/8/8/8/5N2/8/4K3[N] w - - 0 1", state.generate_fen());
+
+        undo_move(memento, &mut state);
+
+        assert_eq!("4k3/8/8/8/8/8/8/4K3[N] w - - 0 1", state.generate_fen());
+    }
+
+    #[test]
+    fn undo_atomic_capture() {
+        let mut state = GameState::from_fen_with_variant(
+            "r3k3/1p6/1Nb5/8/8/8/8/4K3 w - - 0 1",
+            Variant::Atomic,
+        );
+        let requested_move = AttackMove(Coordinate::B6, Coordinate::A8, Tile::FIRST_KNIGHT);
+
+        let memento = perform_move_for(&requested_move, &mut state, true);
+
+        assert_eq!("4k3/1p6/2b5/8/8/8/8/4K3 w - - 0 1", state.generate_fen());
+
+        undo_move(memento, &mut state);
+
+        assert_eq!("r3k3/1p6/1Nb5/8/8/8/8/4K3 w - - 0 1", state.generate_fen());
+    }
+
+    #[test]
+    fn atomic_capture_explodes_adjacent_pieces_but_not_pawns() {
+        let mut state = GameState::from_fen_with_variant(
+            "4k3/8/2pnb3/2RqP3/2PQn3/8/8/4K3 w - - 0 1",
+            Variant::Atomic,
+        );
+
+        resolve_move(
+            &AttackMove(Coordinate::D4, Coordinate::D5, Tile::FIRST_QUEEN),
+            &mut state,
+        );
+
+        assert_eq!("4k3/8/2p5/4P3/2P5/8/8/4K3 b - - 0 1", state.generate_fen());
+    }
+
+    #[test]
+    fn resolving_drop_removes_piece_from_pocket() {
+        let mut state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[Nn] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+
+        resolve_move(&Drop(Tile::FIRST_KNIGHT, Coordinate::F3), &mut state);
+
+        assert_eq!("4k3/8/8/8/8/5N2/8/4K3[n] b - - 0 1", state.generate_fen());
+    }
+
+    #[test]
+    fn capturing_promoted_piece_adds_pawn_to_pocket() {
+        let mut state = GameState::from_fen_with_variant(
+            "3qk3/8/8/8/8/8/8/3QK3[] b - - 0 1",
+            Variant::Crazyhouse,
+        );
+        state.promoted_pieces.push(Coordinate::D1);
+
+        resolve_move(
+            &AttackMove(Coordinate::D8, Coordinate::D1, Tile::SECOND_QUEEN),
+            &mut state,
+        );
+
+        assert_eq!(vec![Tile::SECOND_PAWN], state.captured_pieces.pocket(false));
+    }
+
+    #[test]
+    fn promoted_piece_is_still_promoted_after_moving() {
+        let mut state = GameState::from_fen_with_variant(
+            "4k3/2P5/8/8/8/8/8/4K3[] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+
+        resolve_move(
+            &PawnPromotion(Coordinate::C8, Tile::FIRST_QUEEN),
+            &mut state,
+        );
+        resolve_move(
+            &RegularMove(Coordinate::E8, Coordinate::E7, Tile::SECOND_KING),
+            &mut state,
+        );
+        resolve_move(
+            &RegularMove(Coordinate::C8, Coordinate::C4, Tile::FIRST_QUEEN),
+            &mut state,
+        );
+
+        assert_eq!(vec![Coordinate::C4], state.promoted_pieces);
+    }
 }
@@ -10,13 +10,13 @@ pub fn generate_knight_moves(
     game_state: &GameState,
     is_for_first_player: bool,
 ) {
-    KNIGHT_STATIC_TRANSFORMERS.map(|transformer| {
+    for transformer in KNIGHT_STATIC_TRANSFORMERS {
         if let Some(m) =
             generate_static_move_if_legal(knight, transformer, game_state, is_for_first_player)
         {
             available_moves.push(m)
         }
-    });
+    }
 }
 
 pub fn generate_king_moves(
@@ -25,13 +25,13 @@ pub fn generate_king_moves(
     game_state: &GameState,
     is_for_first_player: bool,
 ) {
-    KING_STATIC_TRANSFORMERS.map(|transformer| {
+    for transformer in KING_STATIC_TRANSFORMERS {
         if let Some(m) =
             generate_static_move_if_legal(king, transformer, game_state, is_for_first_player)
         {
             available_moves.push(m)
         }
-    });
+    }
 }
 
 fn generate_static_move_if_legal(
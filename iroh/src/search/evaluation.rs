@@ -1,3 +1,212 @@
+use crate::heuristics::checkmates::VERY_BIG_NUMBER;
+use crate::moves::Move;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+///Score for delivering checkmate at the root, every ply further away scores one less
+pub(crate) const MATE: i32 = VERY_BIG_NUMBER;
+///Searches never go deeper than this many plies, so any score past this is a checkmate
+pub(crate) const MATE_THRESHOLD: i32 = MATE - u8::MAX as i32 - 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Evaluation {
     pub best_move: String,
+    pub score: Score,
+    ///Number of plies the best move was searched to
+    pub depth: u8,
+    ///Deepest ply reached, including the quiescence search
+    pub seldepth: u8,
+    pub nodes: u64,
+    pub elapsed: Duration,
+    pub nodes_per_second: u64,
+    ///The moves both players are expected to play, starting with the best move
+    pub principal_variation: Vec<Move>,
+    ///The best `SearchOptions::multi_pv` moves, best first. The first line is the same as the
+    ///score and principal variation above.
+    pub lines: Vec<Line>,
+}
+
+impl Evaluation {
+    pub fn principal_variation_san(&self) -> Vec<String> {
+        generate_sans(&self.principal_variation)
+    }
+
+    ///The reply the search expects from the opponent, to ponder on
+    pub fn ponder_move(&self) -> Option<&Move> {
+        self.principal_variation.get(1)
+    }
+}
+
+///One candidate move, with its own score and the moves expected to follow it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub score: Score,
+    pub principal_variation: Vec<Move>,
+}
+
+impl Line {
+    pub fn principal_variation_san(&self) -> Vec<String> {
+        generate_sans(&self.principal_variation)
+    }
+}
+
+fn generate_sans(moves: &[Move]) -> Vec<String> {
+    moves
+        .iter()
+        .map(|possible_move| possible_move.generate_san())
+        .collect()
+}
+
+///Score of the position from the point of view of the player to move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Score {
+    ///Hundredths of a pawn
+    Centipawns(i32),
+    ///Moves until checkmate. Positive when the player to move delivers the checkmate, negative
+    ///when they are checkmated.
+    Mate(i32),
+}
+
+impl Score {
+    ///Converts a search value, which is for the player to move, given what the heuristics value a
+    ///pawn at
+    pub(crate) fn from_value(value: i32, pawn_value: i32) -> Score {
+        if value > MATE_THRESHOLD {
+            let plies = MATE - value;
+            Score::Mate((plies + 1) / 2)
+        } else if value < -MATE_THRESHOLD {
+            let plies = MATE + value;
+            Score::Mate(-(plies + 1) / 2)
+        } else {
+            Score::Centipawns(value * 100 / pawn_value)
+        }
+    }
+
+    ///Number of moves until checkmate, negative when the player to move is checkmated
+    pub fn mate_in(&self) -> Option<i32> {
+        match self {
+            Score::Centipawns(_) => None,
+            Score::Mate(moves) => Some(*moves),
+        }
+    }
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Score::Centipawns(centipawns) => write!(f, "cp {}", centipawns),
+            Score::Mate(moves) => write!(f, "mate {}", moves),
+        }
+    }
+}
+
+pub(crate) fn nodes_per_second(nodes: u64, elapsed: Duration) -> u64 {
+    let micros = elapsed.as_micros().max(1) as u64;
+    nodes.saturating_mul(1_000_000) / micros
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::coordinates::Coordinate;
+    use crate::state::tile::Tile;
+
+    #[test]
+    fn centipawn_score_displays_like_uci() {
+        assert_eq!("cp -150", format!("{}", Score::Centipawns(-150)));
+    }
+
+    #[test]
+    fn mate_score_displays_like_uci() {
+        assert_eq!("mate 3", format!("{}", Score::Mate(3)));
+    }
+
+    #[test]
+    fn value_is_converted_to_centipawns() {
+        let result = Score::from_value(33, 11);
+
+        assert_eq!(Score::Centipawns(300), result);
+    }
+
+    #[test]
+    fn checkmate_next_ply_is_mate_in_one() {
+        let result = Score::from_value(MATE - 1, 11);
+
+        assert_eq!(Some(1), result.mate_in());
+    }
+
+    #[test]
+    fn checkmate_in_three_plies_is_mate_in_two() {
+        let result = Score::from_value(MATE - 3, 11);
+
+        assert_eq!(Score::Mate(2), result);
+    }
+
+    #[test]
+    fn being_checkmated_in_two_plies_is_mated_in_one() {
+        let result = Score::from_value(-MATE + 2, 11);
+
+        assert_eq!(Score::Mate(-1), result);
+    }
+
+    #[test]
+    fn centipawn_score_is_not_mate() {
+        assert_eq!(None, Score::Centipawns(900).mate_in());
+    }
+
+    #[test]
+    fn nodes_per_second_is_based_on_elapsed_time() {
+        let result = nodes_per_second(500, Duration::from_millis(250));
+
+        assert_eq!(2000, result);
+    }
+
+    #[test]
+    fn nodes_per_second_does_not_divide_by_zero() {
+        let result = nodes_per_second(500, Duration::ZERO);
+
+        assert_eq!(500_000_000, result);
+    }
+
+    #[test]
+    fn principal_variation_is_available_as_san() {
+        let evaluation = Evaluation {
+            best_move: String::from("e4"),
+            score: Score::Centipawns(0),
+            depth: 2,
+            seldepth: 2,
+            nodes: 0,
+            elapsed: Duration::ZERO,
+            nodes_per_second: 0,
+            principal_variation: vec![
+                Move::PawnMove(Coordinate::E2, Coordinate::E4),
+                Move::RegularMove(Coordinate::G8, Coordinate::F6, Tile::SECOND_KNIGHT),
+            ],
+            lines: vec![],
+        };
+
+        assert_eq!(vec!["e4", "Nf6"], evaluation.principal_variation_san());
+        assert_eq!(
+            Some(&Move::RegularMove(
+                Coordinate::G8,
+                Coordinate::F6,
+                Tile::SECOND_KNIGHT
+            )),
+            evaluation.ponder_move()
+        );
+    }
+
+    #[test]
+    fn line_principal_variation_is_available_as_san() {
+        let line = Line {
+            score: Score::Mate(1),
+            principal_variation: vec![Move::RegularMove(
+                Coordinate::A5,
+                Coordinate::A8,
+                Tile::FIRST_ROOK,
+            )],
+        };
+
+        assert_eq!(vec!["Ra8"], line.principal_variation_san());
+    }
 }
@@ -0,0 +1,217 @@
+use crate::game::Game;
+use crate::moves::Move;
+use crate::state::coordinates::Coordinate;
+
+///Most plies a single line from the root can be extended by
+const DEFAULT_MAX_PER_LINE: u8 = 8;
+
+///Forcing moves that are searched one ply deeper than the rest, so that the search doesn't stop
+///just before the line is resolved. The extensions along any one line are capped, as a series of
+///checks could otherwise be extended without end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchExtensions {
+    ///Moves that give check
+    pub check: bool,
+    ///Captures on the square where the opponent just captured
+    pub recapture: bool,
+    ///Pawn moves to the seventh rank, where no opposing pawn can stop them, so they are passed
+    pub passed_pawn: bool,
+    ///Most plies a single line from the root can be extended by
+    pub max_per_line: u8,
+}
+
+impl SearchExtensions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    ///Every line is searched to the same depth
+    pub fn none() -> Self {
+        SearchExtensions {
+            check: false,
+            recapture: false,
+            passed_pawn: false,
+            max_per_line: 0,
+        }
+    }
+
+    pub fn with_check(mut self, check: bool) -> Self {
+        self.check = check;
+        self
+    }
+
+    pub fn with_recapture(mut self, recapture: bool) -> Self {
+        self.recapture = recapture;
+        self
+    }
+
+    pub fn with_passed_pawn(mut self, passed_pawn: bool) -> Self {
+        self.passed_pawn = passed_pawn;
+        self
+    }
+
+    pub fn with_max_per_line(mut self, max_per_line: u8) -> Self {
+        self.max_per_line = max_per_line;
+        self
+    }
+
+    ///Plies to search `possible_move` deeper by, given the move before it and the plies the line
+    ///has already been extended by
+    pub(crate) fn extension(
+        &self,
+        possible_move: &Move,
+        move_result: &Game,
+        previous_move: Option<&Move>,
+        is_first_player_move: bool,
+        line_extensions: u8,
+    ) -> u8 {
+        let is_forcing = self.check && is_check(move_result)
+            || self.recapture && is_recapture(possible_move, previous_move)
+            || self.passed_pawn && is_pawn_to_seventh(possible_move, is_first_player_move);
+        if is_forcing && line_extensions < self.max_per_line {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+impl Default for SearchExtensions {
+    fn default() -> Self {
+        SearchExtensions {
+            check: true,
+            recapture: true,
+            passed_pawn: true,
+            max_per_line: DEFAULT_MAX_PER_LINE,
+        }
+    }
+}
+
+fn is_check(move_result: &Game) -> bool {
+    matches!(move_result, Game::Ongoing { state } if state.is_check(state.is_first_player_turn))
+}
+
+fn is_recapture(possible_move: &Move, previous_move: Option<&Move>) -> bool {
+    match (
+        capture_square(possible_move),
+        previous_move.and_then(capture_square),
+    ) {
+        (Some(square), Some(previous_square)) => square == previous_square,
+        _ => false,
+    }
+}
+
+///Square of the piece being taken. En passant takes a pawn on another square, which can't have
+///just captured anything.
+fn capture_square(possible_move: &Move) -> Option<Coordinate> {
+    match *possible_move {
+        Move::AttackMove(_, to, _)
+        | Move::PawnAttackMove(_, to)
+        | Move::PawnAttackPromotion(_, to, _) => Some(to),
+        _ => None,
+    }
+}
+
+fn is_pawn_to_seventh(possible_move: &Move, is_first_player_move: bool) -> bool {
+    match *possible_move {
+        Move::PawnMove(_, to) | Move::PawnAttackMove(_, to) if is_first_player_move => {
+            to.is_rank_7()
+        }
+        Move::PawnMove(_, to) | Move::PawnAttackMove(_, to) => to.is_rank_2(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::tile::Tile;
+
+    #[test]
+    fn move_giving_check_is_extended() {
+        let game = Game::from_fen("3k4/8/8/8/8/8/8/R3K3 w - - 0 1");
+        let possible_move = Move::RegularMove(Coordinate::A1, Coordinate::A8, Tile::FIRST_ROOK);
+        let move_result = game.make_move(&possible_move);
+
+        let result = SearchExtensions::new().extension(&possible_move, &move_result, None, true, 0);
+
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn quiet_move_is_not_extended() {
+        let game = Game::from_fen("3k4/8/8/8/8/8/8/R3K3 w - - 0 1");
+        let possible_move = Move::RegularMove(Coordinate::A1, Coordinate::A7, Tile::FIRST_ROOK);
+        let move_result = game.make_move(&possible_move);
+
+        let result = SearchExtensions::new().extension(&possible_move, &move_result, None, true, 0);
+
+        assert_eq!(0, result);
+    }
+
+    #[test]
+    fn recapture_is_extended() {
+        let game = Game::from_fen("3k4/8/8/8/8/2p5/1P6/4K3 w - - 0 1");
+        let previous_move = Move::AttackMove(Coordinate::D4, Coordinate::C3, Tile::SECOND_KNIGHT);
+        let possible_move = Move::PawnAttackMove(Coordinate::B2, Coordinate::C3);
+        let move_result = game.make_move(&possible_move);
+
+        let result = SearchExtensions::new().extension(
+            &possible_move,
+            &move_result,
+            Some(&previous_move),
+            true,
+            0,
+        );
+
+        assert_eq!(1, result);
+    }
+
+    #[test]
+    fn capture_on_another_square_is_not_a_recapture() {
+        let previous_move = Move::AttackMove(Coordinate::D4, Coordinate::C3, Tile::SECOND_KNIGHT);
+        let possible_move = Move::PawnAttackMove(Coordinate::B2, Coordinate::A3);
+
+        assert!(!is_recapture(&possible_move, Some(&previous_move)));
+    }
+
+    #[test]
+    fn pawn_to_seventh_is_extended_for_either_player() {
+        assert!(is_pawn_to_seventh(
+            &Move::PawnMove(Coordinate::B6, Coordinate::B7),
+            true
+        ));
+        assert!(is_pawn_to_seventh(
+            &Move::PawnMove(Coordinate::B3, Coordinate::B2),
+            false
+        ));
+        assert!(!is_pawn_to_seventh(
+            &Move::PawnMove(Coordinate::B5, Coordinate::B6),
+            true
+        ));
+    }
+
+    #[test]
+    fn line_is_not_extended_beyond_max() {
+        let game = Game::from_fen("3k4/8/8/8/8/8/8/R3K3 w - - 0 1");
+        let possible_move = Move::RegularMove(Coordinate::A1, Coordinate::A8, Tile::FIRST_ROOK);
+        let move_result = game.make_move(&possible_move);
+        let extensions = SearchExtensions::new().with_max_per_line(2);
+
+        let result = extensions.extension(&possible_move, &move_result, None, true, 2);
+
+        assert_eq!(0, result);
+    }
+
+    #[test]
+    fn disabled_extensions_extend_nothing() {
+        let game = Game::from_fen("3k4/8/8/8/8/8/8/R3K3 w - - 0 1");
+        let possible_move = Move::RegularMove(Coordinate::A1, Coordinate::A8, Tile::FIRST_ROOK);
+        let move_result = game.make_move(&possible_move);
+
+        let result =
+            SearchExtensions::none().extension(&possible_move, &move_result, None, true, 0);
+
+        assert_eq!(0, result);
+    }
+}
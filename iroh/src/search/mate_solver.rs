@@ -0,0 +1,342 @@
+use crate::game::Game;
+use crate::moves::Move;
+use crate::search::no_move_error::NoMoveError;
+use crate::search::search_limits::SearchLimits;
+use std::time::Instant;
+
+///Proof and disproof numbers of a node that has been solved
+const INFINITE: u32 = u32::MAX;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MateSolution {
+    ///The attacker's moves and the defender's best replies, ending in checkmate
+    Mate(Vec<Move>),
+    ///There is no forced mate within the given number of moves, using only checking moves
+    NoMate,
+    ///The node or time limit was reached, or the search was stopped, before either was proven
+    Unknown,
+}
+
+impl MateSolution {
+    ///Number of the attacker's moves needed to deliver mate
+    pub fn mate_in(&self) -> Option<usize> {
+        match self {
+            MateSolution::Mate(line) => Some(line.len().div_ceil(2)),
+            _ => None,
+        }
+    }
+
+    pub fn line_san(&self) -> Vec<String> {
+        match self {
+            MateSolution::Mate(line) => line
+                .iter()
+                .map(|possible_move| possible_move.generate_san())
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+///Proves whether the player to move can force mate within `max_moves` of their moves, using
+///proof-number search. Only checking moves are tried for the attacker, so every reply is forced,
+///which lets it look much deeper than the normal search. Mates that need a quiet move aren't
+///found. The shortest mate is returned, with the defender's longest resistance. A win by the
+///variant's rules counts as mate. Only the node and hard time limits, and the stop handle, apply.
+pub fn find_mate(
+    game: &Game,
+    max_moves: u8,
+    limits: &SearchLimits,
+) -> Result<MateSolution, NoMoveError> {
+    if let Some(error) = NoMoveError::from_game(game) {
+        return Err(error);
+    }
+    let mut solver = MateSolver {
+        is_attacker_first_player: game.unwrap().is_first_player_turn,
+        limits,
+        deadline: limits
+            .time_budget(game.unwrap().is_first_player_turn)
+            .map(|budget| Instant::now() + budget.hard),
+        nodes: 0,
+        tree: vec![],
+    };
+
+    //Each mate length is proven or disproven before trying a longer one, so the first is shortest
+    for moves in 1..=max_moves {
+        match solver.solve(game, moves) {
+            MateSolution::NoMate => continue,
+            solution => return Ok(solution),
+        }
+    }
+    Ok(MateSolution::NoMate)
+}
+
+struct Node {
+    game: Game,
+    last_move: Option<Move>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    is_attacker_turn: bool,
+    ///Attacker moves left to deliver mate in
+    moves_left: u8,
+    ///Least number of leaves that need to be proven to prove the attacker mates
+    proof: u32,
+    ///Least number of leaves that need to be disproven to prove the attacker doesn't
+    disproof: u32,
+    is_expanded: bool,
+}
+
+struct MateSolver<'a> {
+    is_attacker_first_player: bool,
+    limits: &'a SearchLimits,
+    deadline: Option<Instant>,
+    nodes: u64,
+    tree: Vec<Node>,
+}
+
+impl MateSolver<'_> {
+    fn solve(&mut self, game: &Game, moves: u8) -> MateSolution {
+        self.tree.clear();
+        self.tree.push(Node {
+            game: game.clone(),
+            last_move: None,
+            parent: None,
+            children: vec![],
+            is_attacker_turn: true,
+            moves_left: moves,
+            proof: 1,
+            disproof: 1,
+            is_expanded: false,
+        });
+
+        while self.tree[0].proof != 0 && self.tree[0].disproof != 0 {
+            if self.is_out_of_time_or_nodes() {
+                return MateSolution::Unknown;
+            }
+            let most_proving = self.most_proving_node();
+            self.expand(most_proving);
+            self.update_ancestors(most_proving);
+        }
+
+        if self.tree[0].proof == 0 {
+            MateSolution::Mate(self.mating_line())
+        } else {
+            MateSolution::NoMate
+        }
+    }
+
+    fn is_out_of_time_or_nodes(&self) -> bool {
+        self.limits.is_stopped()
+            || self.limits.is_node_limit_reached(self.nodes)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    ///The attacker picks the move that is easiest to prove and the defender the move that is
+    ///easiest to disprove, so the leaf reached can settle the most
+    fn most_proving_node(&self) -> usize {
+        let mut index = 0;
+        while self.tree[index].is_expanded {
+            let node = &self.tree[index];
+            index = *node
+                .children
+                .iter()
+                .min_by_key(|&&child| {
+                    if node.is_attacker_turn {
+                        self.tree[child].proof
+                    } else {
+                        self.tree[child].disproof
+                    }
+                })
+                .expect("Only unsolved nodes are selected, and they have children");
+        }
+        index
+    }
+
+    fn expand(&mut self, index: usize) {
+        let node = &self.tree[index];
+        let is_attacker_turn = node.is_attacker_turn;
+        let moves_left = if is_attacker_turn {
+            node.moves_left - 1
+        } else {
+            node.moves_left
+        };
+        let children: Vec<Node> = node
+            .game
+            .unwrap()
+            .possible_moves
+            .iter()
+            .map(|possible_move| (possible_move, node.game.make_move(possible_move)))
+            .filter(|(_, game)| !is_attacker_turn || self.is_check_or_win(game))
+            .map(|(possible_move, game)| {
+                let (proof, disproof) = self.initial_numbers(&game, !is_attacker_turn, moves_left);
+                Node {
+                    game,
+                    last_move: Some(possible_move.clone()),
+                    parent: Some(index),
+                    children: vec![],
+                    is_attacker_turn: !is_attacker_turn,
+                    moves_left,
+                    proof,
+                    disproof,
+                    is_expanded: false,
+                }
+            })
+            .collect();
+
+        self.nodes += children.len() as u64;
+        let first_child = self.tree.len();
+        self.tree.extend(children);
+        let last_child = self.tree.len();
+        let node = &mut self.tree[index];
+        node.children = (first_child..last_child).collect();
+        node.is_expanded = true;
+    }
+
+    fn is_check_or_win(&self, game: &Game) -> bool {
+        match game {
+            Game::Win {
+                is_first_player_win,
+                ..
+            } => *is_first_player_win == self.is_attacker_first_player,
+            Game::Ongoing { state } => state.is_check(state.is_first_player_turn),
+            Game::Draw { .. } | Game::IllegalMove { .. } => false,
+        }
+    }
+
+    fn initial_numbers(&self, game: &Game, is_attacker_turn: bool, moves_left: u8) -> (u32, u32) {
+        match game {
+            Game::Win {
+                is_first_player_win,
+                ..
+            } if *is_first_player_win == self.is_attacker_first_player => (0, INFINITE),
+            Game::Ongoing { .. } if !is_attacker_turn || moves_left > 0 => (1, 1),
+            _ => (INFINITE, 0),
+        }
+    }
+
+    fn update_ancestors(&mut self, index: usize) {
+        let mut current = Some(index);
+        while let Some(index) = current {
+            let node = &self.tree[index];
+            let proofs = node.children.iter().map(|&child| self.tree[child].proof);
+            let disproofs = node.children.iter().map(|&child| self.tree[child].disproof);
+            let (proof, disproof) = if node.is_attacker_turn {
+                (proofs.min().unwrap_or(INFINITE), saturating_sum(disproofs))
+            } else {
+                (saturating_sum(proofs), disproofs.min().unwrap_or(INFINITE))
+            };
+            let node = &mut self.tree[index];
+            node.proof = proof;
+            node.disproof = disproof;
+            current = node.parent;
+        }
+    }
+
+    ///Plies until mate from a proven node, with the attacker hurrying and the defender delaying
+    fn plies_to_mate(&self, index: usize) -> usize {
+        let node = &self.tree[index];
+        let plies = node
+            .children
+            .iter()
+            .filter(|&&child| self.tree[child].proof == 0)
+            .map(|&child| self.plies_to_mate(child) + 1);
+        if node.is_attacker_turn {
+            plies.min().unwrap_or(0)
+        } else {
+            plies.max().unwrap_or(0)
+        }
+    }
+
+    fn mating_line(&self) -> Vec<Move> {
+        let mut line = vec![];
+        let mut index = 0;
+        while !self.tree[index].children.is_empty() {
+            let node = &self.tree[index];
+            let proven = node
+                .children
+                .iter()
+                .copied()
+                .filter(|&child| self.tree[child].proof == 0);
+            index = if node.is_attacker_turn {
+                proven.min_by_key(|&child| self.plies_to_mate(child))
+            } else {
+                proven.max_by_key(|&child| self.plies_to_mate(child))
+            }
+            .expect("A proven node has a proven child");
+            line.push(
+                self.tree[index]
+                    .last_move
+                    .clone()
+                    .expect("Every child has a move"),
+            );
+        }
+        line
+    }
+}
+
+fn saturating_sum(numbers: impl Iterator<Item = u32>) -> u32 {
+    numbers.fold(0, |sum, number| sum.saturating_add(number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_mate_in_one() {
+        let game = Game::from_fen("k7/8/1K6/8/8/8/8/7R w - - 0 1");
+
+        let result = find_mate(&game, 1, &SearchLimits::new()).unwrap();
+
+        assert_eq!(vec!["Rh8"], result.line_san());
+        assert_eq!(Some(1), result.mate_in());
+    }
+
+    #[test]
+    fn finds_mate_in_two_with_forced_reply() {
+        let game = Game::from_fen("1r4k1/5ppp/8/8/8/8/3Q1PPP/3R2K1 w - - 0 1");
+
+        let result = find_mate(&game, 3, &SearchLimits::new()).unwrap();
+
+        assert_eq!(vec!["Qd8", "Rxd8", "Rxd8"], result.line_san());
+        assert_eq!(Some(2), result.mate_in());
+    }
+
+    #[test]
+    fn proves_there_is_no_mate_in_one() {
+        let game = Game::from_fen("1r4k1/5ppp/8/8/8/8/3Q1PPP/3R2K1 w - - 0 1");
+
+        let result = find_mate(&game, 1, &SearchLimits::new()).unwrap();
+
+        assert_eq!(MateSolution::NoMate, result);
+        assert_eq!(None, result.mate_in());
+    }
+
+    #[test]
+    fn quiet_moves_are_not_tried() {
+        let game = Game::from_fen("k7/8/2K5/8/8/8/8/7R w - - 0 1");
+
+        let result = find_mate(&game, 2, &SearchLimits::new()).unwrap();
+
+        assert_eq!(MateSolution::NoMate, result);
+    }
+
+    #[test]
+    fn node_limit_leaves_mate_unknown() {
+        let game = Game::from_fen("1r4k1/5ppp/8/8/8/8/3Q1PPP/3R2K1 w - - 0 1");
+
+        let result = find_mate(&game, 3, &SearchLimits::new().with_max_nodes(1)).unwrap();
+
+        assert_eq!(MateSolution::Unknown, result);
+    }
+
+    #[test]
+    fn finished_game_has_no_move() {
+        let game = Game::from_fen("k6R/8/1K6/8/8/8/8/8 b - - 0 1");
+
+        let result = find_mate(&game, 1, &SearchLimits::new());
+
+        assert_eq!(Err(NoMoveError::Checkmate), result);
+    }
+}
@@ -0,0 +1,550 @@
+use crate::game::Game;
+use crate::heuristics::Heuristics;
+use crate::moves::Move;
+use crate::search::evaluation::{nodes_per_second, Evaluation, Line, Score, MATE, MATE_THRESHOLD};
+use crate::search::no_move_error::NoMoveError;
+use crate::search::random::SplitMix64;
+use crate::search::search_config::SearchConfig;
+use crate::search::search_limits::SearchLimits;
+use crate::search::search_observer::SearchObserver;
+use crate::search::searcher::Searcher;
+use std::time::Instant;
+
+///The usual UCT exploration constant, the square root of two
+pub const DEFAULT_EXPLORATION: f64 = std::f64::consts::SQRT_2;
+const DEFAULT_PLAYOUT_PLIES: u16 = 16;
+///Nodes visited when the limits don't say when to stop
+const DEFAULT_MAX_NODES: u64 = 20_000;
+///Progress is reported each time this many more iterations have been run
+const REPORT_EVERY_ITERATIONS: u32 = 256;
+///How many pawns ahead makes a position about three times as likely to be won as lost
+const PAWNS_PER_WIN_ODDS: f64 = 4.0;
+
+///How moves are chosen during a playout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayoutPolicy {
+    ///Any legal move, all equally likely
+    Random,
+    ///The move the heuristics value most, ties broken at random
+    HeuristicGuided,
+}
+
+///How a newly added leaf is valued
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeafEvaluation {
+    ///Play the game out for up to `playout_plies`, valuing where it ends with the heuristics
+    Playout,
+    ///Value the leaf with the heuristics straight away
+    Heuristics,
+}
+
+///Monte Carlo tree search, using UCT to choose which moves to explore. The best move is the one
+///explored most. The node limit, soft time limit and stop handle apply; the depth limit doesn't.
+///With no node or time limit a fixed number of nodes are visited.
+pub struct MctsSearcher {
+    config: SearchConfig,
+    exploration: f64,
+    playout_policy: PlayoutPolicy,
+    leaf_evaluation: LeafEvaluation,
+    playout_plies: u16,
+    seed: Option<u64>,
+}
+
+impl Default for MctsSearcher {
+    fn default() -> Self {
+        MctsSearcher::new(SearchConfig::default())
+    }
+}
+
+impl MctsSearcher {
+    pub fn new(config: SearchConfig) -> Self {
+        MctsSearcher {
+            config,
+            exploration: DEFAULT_EXPLORATION,
+            playout_policy: PlayoutPolicy::Random,
+            leaf_evaluation: LeafEvaluation::Playout,
+            playout_plies: DEFAULT_PLAYOUT_PLIES,
+            seed: None,
+        }
+    }
+
+    ///Higher values try less explored moves more often, lower values focus on the best so far
+    pub fn with_exploration(mut self, exploration: f64) -> Self {
+        self.exploration = exploration;
+        self
+    }
+
+    pub fn with_playout_policy(mut self, playout_policy: PlayoutPolicy) -> Self {
+        self.playout_policy = playout_policy;
+        self
+    }
+
+    pub fn with_leaf_evaluation(mut self, leaf_evaluation: LeafEvaluation) -> Self {
+        self.leaf_evaluation = leaf_evaluation;
+        self
+    }
+
+    pub fn with_playout_plies(mut self, playout_plies: u16) -> Self {
+        self.playout_plies = playout_plies;
+        self
+    }
+
+    ///Makes the search repeatable, otherwise it changes every search
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+impl Searcher for MctsSearcher {
+    fn search(
+        &mut self,
+        game: &mut Game,
+        limits: &SearchLimits,
+        observer: &mut dyn SearchObserver,
+    ) -> Result<Evaluation, NoMoveError> {
+        if let Some(error) = NoMoveError::from_game(game) {
+            return Err(error);
+        }
+        let start = Instant::now();
+        let time_budget = limits.time_budget(game.unwrap().is_first_player_turn);
+        let max_nodes = match (limits.max_nodes, time_budget) {
+            (None, None) => Some(DEFAULT_MAX_NODES),
+            (max_nodes, _) => max_nodes,
+        };
+        let mut search = MctsSearch {
+            searcher: self,
+            random: self
+                .seed
+                .map_or_else(SplitMix64::from_time, SplitMix64::new),
+            tree: vec![Node::new(game.clone(), None, None)],
+            nodes: 0,
+            seldepth: 0,
+            start,
+        };
+
+        let mut iterations = 0;
+        loop {
+            let is_time_up = time_budget.is_some_and(|budget| {
+                limits
+                    .clock_start(start)
+                    .is_some_and(|clock_start| clock_start.elapsed() >= budget.soft)
+            });
+            if limits.is_stopped()
+                || max_nodes.is_some_and(|max_nodes| search.nodes >= max_nodes)
+                || is_time_up
+            {
+                break;
+            }
+            search.iterate();
+            iterations += 1;
+            if iterations % REPORT_EVERY_ITERATIONS == 0 {
+                observer.on_iteration_complete(&search.evaluation());
+            }
+        }
+
+        if search.tree[0].children.is_empty() {
+            //Stopped before anything was explored, so any move will do
+            search.expand(0);
+        }
+        let evaluation = search.evaluation();
+        observer.on_iteration_complete(&evaluation);
+        Ok(evaluation)
+    }
+}
+
+struct Node {
+    game: Game,
+    last_move: Option<Move>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried_moves: Vec<Move>,
+    visits: u32,
+    ///Sum of the results for the player who made `last_move`, 1 for a win and 0 for a loss
+    wins: f64,
+}
+
+impl Node {
+    fn new(game: Game, last_move: Option<Move>, parent: Option<usize>) -> Self {
+        let untried_moves = match &game {
+            Game::Ongoing { state } => state.possible_moves.clone(),
+            _ => vec![],
+        };
+        Node {
+            game,
+            last_move,
+            parent,
+            children: vec![],
+            untried_moves,
+            visits: 0,
+            wins: 0.0,
+        }
+    }
+
+    fn win_rate(&self) -> f64 {
+        if self.visits == 0 {
+            0.5
+        } else {
+            self.wins / self.visits as f64
+        }
+    }
+}
+
+struct MctsSearch<'a> {
+    searcher: &'a MctsSearcher,
+    random: SplitMix64,
+    tree: Vec<Node>,
+    nodes: u64,
+    seldepth: u8,
+    start: Instant,
+}
+
+impl MctsSearch<'_> {
+    fn iterate(&mut self) {
+        let (selected, depth) = self.select();
+        let leaf = self.expand(selected);
+        self.seldepth = self.seldepth.max(depth + (leaf != selected) as u8);
+        let result = self.simulate(leaf);
+        self.backpropagate(leaf, result);
+    }
+
+    ///Descends through fully expanded nodes to the one that best balances winning and being
+    ///unexplored
+    fn select(&self) -> (usize, u8) {
+        let mut index = 0;
+        let mut depth = 0;
+        while self.tree[index].untried_moves.is_empty() && !self.tree[index].children.is_empty() {
+            let parent_visits = self.tree[index].visits as f64;
+            index = *self.tree[index]
+                .children
+                .iter()
+                .max_by(|&&first, &&second| {
+                    self.uct(first, parent_visits)
+                        .total_cmp(&self.uct(second, parent_visits))
+                })
+                .expect("Node has children");
+            depth += 1;
+        }
+        (index, depth)
+    }
+
+    fn uct(&self, index: usize, parent_visits: f64) -> f64 {
+        let node = &self.tree[index];
+        if node.visits == 0 {
+            return f64::INFINITY;
+        }
+        let visits = node.visits as f64;
+        node.wins / visits + self.searcher.exploration * (parent_visits.ln() / visits).sqrt()
+    }
+
+    ///Adds a child for one of the moves not yet tried, if there are any
+    fn expand(&mut self, index: usize) -> usize {
+        let untried_moves = &mut self.tree[index].untried_moves;
+        if untried_moves.is_empty() {
+            return index;
+        }
+        let chosen = self.random.below(untried_moves.len());
+        let possible_move = untried_moves.swap_remove(chosen);
+        let game = self.tree[index].game.make_move(&possible_move);
+        let child = self.tree.len();
+        self.tree
+            .push(Node::new(game, Some(possible_move), Some(index)));
+        self.tree[index].children.push(child);
+        self.nodes += 1;
+        child
+    }
+
+    ///The result for the player who made the move into the node
+    fn simulate(&mut self, index: usize) -> f64 {
+        let mut game = self.tree[index].game.clone();
+        let is_mover_first_player = !game.unwrap().is_first_player_turn;
+        if self.searcher.leaf_evaluation == LeafEvaluation::Playout {
+            for _ in 0..self.searcher.playout_plies {
+                if !matches!(game, Game::Ongoing { .. }) {
+                    break;
+                }
+                let possible_move = self.playout_move(&game);
+                game = game.make_move(&possible_move);
+                self.nodes += 1;
+            }
+        }
+        self.result(&mut game, is_mover_first_player)
+    }
+
+    fn playout_move(&mut self, game: &Game) -> Move {
+        let possible_moves = &game.unwrap().possible_moves;
+        match self.searcher.playout_policy {
+            PlayoutPolicy::Random => {
+                possible_moves[self.random.below(possible_moves.len())].clone()
+            }
+            PlayoutPolicy::HeuristicGuided => {
+                let heuristics = &self.searcher.config.heuristics;
+                let is_first_player_turn = game.unwrap().is_first_player_turn;
+                let values: Vec<i32> = possible_moves
+                    .iter()
+                    .map(|possible_move| {
+                        let value =
+                            value_for_first_player(&mut game.make_move(possible_move), heuristics);
+                        if is_first_player_turn {
+                            value
+                        } else {
+                            -value
+                        }
+                    })
+                    .collect();
+                let best_value = *values.iter().max().expect("Game is ongoing");
+                let best_moves: Vec<&Move> = possible_moves
+                    .iter()
+                    .zip(values)
+                    .filter(|(_, value)| *value == best_value)
+                    .map(|(possible_move, _)| possible_move)
+                    .collect();
+                best_moves[self.random.below(best_moves.len())].clone()
+            }
+        }
+    }
+
+    ///1 for a win, 0 for a loss and in between for a draw or an unfinished game, which is valued
+    ///by the heuristics
+    fn result(&self, game: &mut Game, is_first_player: bool) -> f64 {
+        match game {
+            Game::Win {
+                is_first_player_win,
+                ..
+            } => (*is_first_player_win == is_first_player) as u8 as f64,
+            Game::Draw { .. } => 0.5,
+            Game::Ongoing { .. } | Game::IllegalMove { .. } => {
+                let heuristics = &self.searcher.config.heuristics;
+                let value = value_for_first_player(game, heuristics) as f64;
+                let value = if is_first_player { value } else { -value };
+                let pawn_value = heuristics.pawn_value() as f64;
+                1.0 / (1.0 + (-value / (PAWNS_PER_WIN_ODDS * pawn_value)).exp())
+            }
+        }
+    }
+
+    fn backpropagate(&mut self, leaf: usize, mut result: f64) {
+        let mut current = Some(leaf);
+        while let Some(index) = current {
+            let node = &mut self.tree[index];
+            node.visits += 1;
+            node.wins += result;
+            result = 1.0 - result;
+            current = node.parent;
+        }
+    }
+
+    ///Root moves explored most first
+    fn ranked_children(&self, index: usize) -> Vec<usize> {
+        let mut children = self.tree[index].children.clone();
+        children.sort_by(|&first, &second| {
+            self.tree[second].visits.cmp(&self.tree[first].visits).then(
+                self.tree[second]
+                    .win_rate()
+                    .total_cmp(&self.tree[first].win_rate()),
+            )
+        });
+        children
+    }
+
+    fn principal_variation(&self, mut index: usize) -> Vec<Move> {
+        let mut principal_variation = vec![];
+        loop {
+            principal_variation.push(
+                self.tree[index]
+                    .last_move
+                    .clone()
+                    .expect("Only the root has no move"),
+            );
+            match self.ranked_children(index).first() {
+                Some(&child) if self.tree[child].visits > 0 => index = child,
+                _ => return principal_variation,
+            }
+        }
+    }
+
+    fn score(&self, index: usize) -> Score {
+        let node = &self.tree[index];
+        let pawn_value = self.searcher.config.heuristics.pawn_value();
+        let value = match node.game {
+            Game::Win {
+                is_first_player_win,
+                ref state,
+            } if is_first_player_win != state.is_first_player_turn => MATE - 1,
+            Game::Win { .. } => -MATE + 1,
+            _ => {
+                let win_rate = node.win_rate().clamp(0.001, 0.999);
+                let pawns = PAWNS_PER_WIN_ODDS * (win_rate / (1.0 - win_rate)).ln();
+                ((pawns * pawn_value as f64).round() as i32).clamp(-MATE_THRESHOLD, MATE_THRESHOLD)
+            }
+        };
+        Score::from_value(value, pawn_value)
+    }
+
+    fn evaluation(&self) -> Evaluation {
+        let lines: Vec<Line> = self
+            .ranked_children(0)
+            .into_iter()
+            .take(self.searcher.config.options.multi_pv.max(1))
+            .map(|child| Line {
+                score: self.score(child),
+                principal_variation: self.principal_variation(child),
+            })
+            .collect();
+        let elapsed = self.start.elapsed();
+        let nodes = self.nodes;
+        Evaluation {
+            best_move: lines[0].principal_variation[0].generate_san(),
+            score: lines[0].score,
+            depth: lines[0].principal_variation.len() as u8,
+            seldepth: self.seldepth,
+            nodes,
+            elapsed,
+            nodes_per_second: nodes_per_second(nodes, elapsed),
+            principal_variation: lines[0].principal_variation.clone(),
+            lines,
+        }
+    }
+}
+
+fn value_for_first_player(game: &mut Game, heuristics: &Heuristics) -> i32 {
+    match game {
+        Game::Win {
+            is_first_player_win,
+            ..
+        } => {
+            if *is_first_player_win {
+                MATE
+            } else {
+                -MATE
+            }
+        }
+        Game::Draw { .. } => 0,
+        Game::Ongoing { .. } | Game::IllegalMove { .. } => heuristics.evaluate(game.unwrap_mut()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::heuristics::weightings::Weightings;
+    use crate::heuristics::HeuristicType;
+    use crate::search::search_observer::NullObserver;
+
+    fn search_with(searcher: MctsSearcher, fen: &str, max_nodes: u64) -> Evaluation {
+        let mut searcher = searcher.with_seed(3);
+        searcher
+            .search(
+                &mut Game::from_fen(fen),
+                &SearchLimits::new().with_max_nodes(max_nodes),
+                &mut NullObserver,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn random_playouts_take_a_free_queen() {
+        let result = search_with(
+            MctsSearcher::default().with_playout_plies(4),
+            "3k4/8/3q4/8/8/3Q4/8/3K4 w - - 0 1",
+            3000,
+        );
+
+        assert_eq!("Qxd6", result.best_move);
+    }
+
+    #[test]
+    fn heuristic_leaves_take_a_free_queen() {
+        let result = search_with(
+            MctsSearcher::default().with_leaf_evaluation(LeafEvaluation::Heuristics),
+            "3k4/8/3q4/8/8/3Q4/8/3K4 w - - 0 1",
+            500,
+        );
+
+        assert_eq!("Qxd6", result.best_move);
+    }
+
+    #[test]
+    fn heuristic_guided_playouts_take_a_free_queen() {
+        let result = search_with(
+            MctsSearcher::default()
+                .with_playout_policy(PlayoutPolicy::HeuristicGuided)
+                .with_playout_plies(2),
+            "3k4/8/3q4/8/8/3Q4/8/3K4 w - - 0 1",
+            1000,
+        );
+
+        assert_eq!("Qxd6", result.best_move);
+    }
+
+    #[test]
+    fn mate_in_one_is_scored_as_mate() {
+        let result = search_with(
+            MctsSearcher::default().with_leaf_evaluation(LeafEvaluation::Heuristics),
+            "k7/8/1K6/8/8/8/8/7R w - - 0 1",
+            500,
+        );
+
+        assert_eq!("Rh8", result.best_move);
+        assert_eq!(Score::Mate(1), result.score);
+    }
+
+    #[test]
+    fn zero_material_weight_still_finds_mate() {
+        let weightings = Weightings::new().push(HeuristicType::Material, 0.0);
+        let result = search_with(
+            MctsSearcher::new(SearchConfig::new().with_weightings(weightings))
+                .with_leaf_evaluation(LeafEvaluation::Heuristics),
+            "k7/8/1K6/8/8/8/8/7R w - - 0 1",
+            500,
+        );
+
+        assert_eq!("Rh8", result.best_move);
+        assert_eq!(Score::Mate(1), result.score);
+    }
+
+    #[test]
+    fn same_seed_gives_same_result() {
+        let first = search_with(
+            MctsSearcher::default(),
+            "3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1",
+            300,
+        );
+        let second = search_with(
+            MctsSearcher::default(),
+            "3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1",
+            300,
+        );
+
+        assert_eq!(first.principal_variation, second.principal_variation);
+        assert_eq!(first.score, second.score);
+    }
+
+    #[test]
+    fn node_limit_is_kept() {
+        let result = search_with(
+            MctsSearcher::default().with_playout_plies(0),
+            "3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1",
+            50,
+        );
+
+        assert_eq!(50, result.nodes);
+    }
+
+    #[test]
+    fn stopped_search_still_has_a_move() {
+        let stop_handle = crate::search::stop_handle::StopHandle::new();
+        stop_handle.stop();
+
+        let result = MctsSearcher::default()
+            .search(
+                &mut Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1"),
+                &SearchLimits::new().with_stop_handle(stop_handle),
+                &mut NullObserver,
+            )
+            .unwrap();
+
+        assert!(!result.best_move.is_empty());
+        assert_eq!(1, result.nodes);
+    }
+}
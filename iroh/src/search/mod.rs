@@ -1,85 +1,886 @@
 use crate::game::Game;
 use crate::heuristics::Heuristics;
-use evaluation::Evaluation;
-use possible_move::PossibleMove;
-use std::collections::BinaryHeap;
+use crate::moves::Move;
+use crate::state::tile::Tile;
+use crate::state::variant::Variant;
+use evaluation::{nodes_per_second, Evaluation, Line, Score, MATE, MATE_THRESHOLD};
+use move_ordering::MoveOrdering;
+use no_move_error::NoMoveError;
+use quiescence::quiescence;
+use search_limits::SearchLimits;
+use search_observer::{NullObserver, SearchObserver};
+use search_options::SearchOptions;
+use skill_level::{SkillLevel, CANDIDATE_MOVES};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use time_management::TimeBudget;
+use transposition_table::{Bound, TranspositionTable};
 
 pub mod evaluation;
-mod possible_move;
+pub mod extensions;
+pub mod mate_solver;
+pub mod mcts;
+mod move_ordering;
+pub mod no_move_error;
+pub mod ponder_handle;
+mod quiescence;
+mod random;
+pub mod search_config;
+pub mod search_limits;
+pub mod search_observer;
+pub mod search_options;
+pub mod searcher;
+pub mod skill_level;
+pub mod stop_handle;
+pub mod time_management;
+pub mod transposition_table;
 
-const MAX_DEPTH: u8 = 2;
+///Larger than any score, so it can be negated safely where `i32::MIN` could not
+const INFINITY: i32 = MATE + 1;
+const NULL_MOVE_MIN_DEPTH: u8 = 3;
+const NULL_MOVE_REDUCTION: u8 = 2;
+const LATE_MOVE_REDUCTION_MIN_DEPTH: u8 = 3;
+const LATE_MOVE_REDUCTION_AFTER_MOVES: usize = 3;
+const LATE_MOVE_REDUCTION: u8 = 1;
+///Failed aspiration searches before the window is dropped altogether
+const ASPIRATION_MAX_FAILURES: u32 = 3;
 
-pub fn search(game: &mut Game) -> Evaluation {
-    let heuristics = Heuristics::new();
-    let mut results: BinaryHeap<PossibleMove> = BinaryHeap::new();
-    let is_first_player = game.unwrap().is_first_player_turn;
+///Searches with the default limits, options and heuristics. A `searcher::Searcher` can be
+///configured instead.
+pub fn search(game: &mut Game) -> Result<Evaluation, NoMoveError> {
+    search_with_limits(game, &SearchLimits::default(), NullObserver)
+}
+
+///Searches one ply deeper on each iteration until `limits.max_depth` is reached, reporting every
+///completed iteration. No new iteration is started once the soft time limit has passed. If the
+///node limit or hard time limit interrupts an iteration, the best move from the last completed
+///depth is returned. The first iteration always runs to completion unless the stop handle is
+///triggered, and either way there is a move to return. With a ponder handle, time limits are
+///ignored until the ponder hit. A game that is already over has no move, and returns why.
+pub fn search_with_limits<O: SearchObserver>(
+    game: &mut Game,
+    limits: &SearchLimits,
+    observer: O,
+) -> Result<Evaluation, NoMoveError> {
+    let mut table = TranspositionTable::default();
+    search_with_table(game, limits, &mut table, observer)
+}
+
+///As `search_with_limits`, but keeps results in the given transposition table so they can be
+///reused by later searches in the same game. Clear the table between games.
+pub fn search_with_table<O: SearchObserver>(
+    game: &mut Game,
+    limits: &SearchLimits,
+    table: &mut TranspositionTable,
+    observer: O,
+) -> Result<Evaluation, NoMoveError> {
+    search_with_options(game, limits, &SearchOptions::default(), table, observer)
+}
+
+///As `search_with_table`, with the optional parts of the search configured by `options`. With
+///more than one thread, helper threads search the same position alongside the main thread and
+///share what they find through the transposition table. The main thread's result is returned.
+pub fn search_with_options<O: SearchObserver>(
+    game: &mut Game,
+    limits: &SearchLimits,
+    options: &SearchOptions,
+    table: &mut TranspositionTable,
+    observer: O,
+) -> Result<Evaluation, NoMoveError> {
+    alpha_beta(game, limits, options, &Heuristics::new(), table, observer)
+}
+
+///Alpha-beta search valuing positions with `heuristics`, which `AlphaBetaSearcher` lets callers
+///choose
+fn alpha_beta<O: SearchObserver>(
+    game: &mut Game,
+    limits: &SearchLimits,
+    options: &SearchOptions,
+    heuristics: &Heuristics,
+    table: &mut TranspositionTable,
+    mut observer: O,
+) -> Result<Evaluation, NoMoveError> {
+    if let Some(error) = NoMoveError::from_game(game) {
+        return Err(error);
+    }
+    let multi_pv = options.multi_pv;
+    //Weaker skill levels search less, and find a few moves to choose between
+    let skill_level = options
+        .skill_level
+        .filter(|skill_level| !skill_level.is_full_strength());
+    let (limits, options) = match skill_level {
+        Some(skill_level) => (
+            skill_level.limit(limits),
+            SearchOptions {
+                multi_pv: options.multi_pv.max(CANDIDATE_MOVES),
+                ..options.clone()
+            },
+        ),
+        None => (limits.clone(), options.clone()),
+    };
+    let (limits, options) = (&limits, &options);
+    table.age();
+    let table: &TranspositionTable = table;
+    let stop = AtomicBool::new(false);
+    let start = Instant::now();
+    let is_first_player_turn = game.unwrap().is_first_player_turn;
+    let time_budget = limits.time_budget(is_first_player_turn);
+    let contempt = options.contempt * heuristics.weighted_material(Tile::FIRST_PAWN) / 100;
+    let new_context = || SearchContext {
+        heuristics,
+        limits,
+        options,
+        table,
+        stop: &stop,
+        time_budget,
+        start,
+        root_is_first_player: is_first_player_turn,
+        contempt,
+        nodes: 0,
+        seldepth: 0,
+        root_best: None,
+        can_be_interrupted: false,
+        min_depth: skill_level.map_or(1, |skill_level| skill_level.min_depth()),
+        ordering: MoveOrdering::new(),
+        line_extensions: 0,
+    };
+
+    thread::scope(|scope| {
+        let helpers: Vec<_> = (1..options.threads.max(1))
+            .map(|helper_index| {
+                let mut helper_game = game.clone();
+                let mut context = new_context();
+                context.can_be_interrupted = true;
+                scope.spawn(move || {
+                    //Half the helpers search one ply ahead, so the threads spread out over depths
+                    let first_depth = 1 + (helper_index % 2) as u8;
+                    iterative_deepening(
+                        &mut helper_game,
+                        &mut context,
+                        first_depth,
+                        &mut NullObserver,
+                    );
+                    context.nodes
+                })
+            })
+            .collect();
+
+        let mut context = new_context();
+        let evaluation = iterative_deepening(game, &mut context, 1, &mut observer);
+        stop.store(true, Ordering::Relaxed);
+        let helper_nodes: u64 = helpers
+            .into_iter()
+            .map(|helper| helper.join().expect("Search thread panicked"))
+            .sum();
+
+        let mut evaluation = evaluation.expect("The main thread always has a move to return");
+        evaluation.nodes = context.nodes + helper_nodes;
+        evaluation.elapsed = start.elapsed();
+        evaluation.nodes_per_second = nodes_per_second(evaluation.nodes, evaluation.elapsed);
+        if let Some(skill_level) = skill_level {
+            play_at_skill_level(&mut evaluation, skill_level, multi_pv);
+        }
+        Ok(evaluation)
+    })
+}
 
-    for possible_move in game.unwrap().possible_moves.iter() {
-        let mut move_result = game.make_move(possible_move);
-        #[cfg(debug_assertions)]
-        println!("Possible move START: {possible_move}");
-        let value = minmax(
+///Makes the line the skill level chooses the best one, keeping as many lines as were asked for
+fn play_at_skill_level(evaluation: &mut Evaluation, skill_level: SkillLevel, multi_pv: usize) {
+    let chosen = evaluation
+        .lines
+        .remove(skill_level.choose_line(&evaluation.lines));
+    evaluation.lines.insert(0, chosen);
+    evaluation.lines.truncate(multi_pv.max(1));
+    let line = &evaluation.lines[0];
+    evaluation.best_move = line.principal_variation[0].generate_san();
+    evaluation.score = line.score;
+    evaluation.principal_variation = line.principal_variation.clone();
+}
+
+///The position to ponder on, after the best move and the reply the search expects. None if the
+///principal variation stops before the reply or the game is over by then.
+pub fn ponder_game(game: &Game, evaluation: &Evaluation) -> Option<Game> {
+    let ponder_move = evaluation.ponder_move()?;
+    let game = game.make_move(&evaluation.principal_variation[0]);
+    if !matches!(game, Game::Ongoing { .. }) {
+        return None;
+    }
+    let game = game.make_move(ponder_move);
+    matches!(game, Game::Ongoing { .. }).then_some(game)
+}
+
+///Searches one ply deeper each iteration, starting from `first_depth`. Returns the evaluation of
+///the last completed iteration. If the first iteration is stopped, the best move found before it
+///stopped is returned with a depth of 0. Helper threads, which may be interrupted at any time, may
+///return nothing.
+fn iterative_deepening(
+    game: &mut Game,
+    context: &mut SearchContext,
+    first_depth: u8,
+    observer: &mut dyn SearchObserver,
+) -> Option<Evaluation> {
+    let mut best_evaluation = None;
+    let mut previous_value = None;
+    let pawn_value = context.heuristics.pawn_value();
+    let max_depth = context.limits.max_depth.max(1);
+
+    for depth in first_depth.min(max_depth)..=max_depth {
+        context.seldepth = 0;
+        match search_lines(game, depth, context, previous_value, observer) {
+            Some(results) => {
+                previous_value = Some(results[0].1);
+                let lines: Vec<Line> = results
+                    .into_iter()
+                    .map(|(best_move, value)| Line {
+                        score: Score::from_value(value, pawn_value),
+                        principal_variation: principal_variation(
+                            game,
+                            best_move,
+                            depth,
+                            context.table,
+                        ),
+                    })
+                    .collect();
+                let elapsed = context.start.elapsed();
+                let evaluation = Evaluation {
+                    best_move: lines[0].principal_variation[0].generate_san(),
+                    score: lines[0].score,
+                    depth,
+                    seldepth: context.seldepth.max(depth),
+                    nodes: context.nodes,
+                    elapsed,
+                    nodes_per_second: nodes_per_second(context.nodes, elapsed),
+                    principal_variation: lines[0].principal_variation.clone(),
+                    lines,
+                };
+                observer.on_iteration_complete(&evaluation);
+                best_evaluation = Some(evaluation);
+                context.can_be_interrupted |= depth >= context.min_depth;
+                if context.can_be_interrupted && context.is_soft_limit_reached() {
+                    break;
+                }
+            }
+            None => {
+                if best_evaluation.is_none() && !context.can_be_interrupted {
+                    best_evaluation = Some(partial_evaluation(game, context, pawn_value));
+                }
+                break;
+            }
+        }
+    }
+
+    best_evaluation
+}
+
+///The best of the root moves searched before the first iteration was stopped, or the first move
+///if none were
+fn partial_evaluation(game: &mut Game, context: &mut SearchContext, pawn_value: i32) -> Evaluation {
+    let (best_move, value) = match context.root_best.take() {
+        Some(root_best) => root_best,
+        None => {
+            let first_move = order(game, context, None, None, 0).remove(0);
+            (first_move, evaluate(game, context))
+        }
+    };
+    let elapsed = context.start.elapsed();
+    let line = Line {
+        score: Score::from_value(value, pawn_value),
+        principal_variation: vec![best_move],
+    };
+    Evaluation {
+        best_move: line.principal_variation[0].generate_san(),
+        score: line.score,
+        depth: 0,
+        seldepth: context.seldepth,
+        nodes: context.nodes,
+        elapsed,
+        nodes_per_second: nodes_per_second(context.nodes, elapsed),
+        principal_variation: line.principal_variation.clone(),
+        lines: vec![line],
+    }
+}
+
+struct SearchContext<'a> {
+    heuristics: &'a Heuristics,
+    limits: &'a SearchLimits,
+    options: &'a SearchOptions,
+    table: &'a TranspositionTable,
+    ///Set once the main thread has finished, to stop the helper threads
+    stop: &'a AtomicBool,
+    time_budget: Option<TimeBudget>,
+    start: Instant,
+    root_is_first_player: bool,
+    ///`SearchOptions::contempt` in the heuristics' units
+    contempt: i32,
+    nodes: u64,
+    seldepth: u8,
+    ///Best root move so far in the current iteration
+    root_best: Option<(Move, i32)>,
+    can_be_interrupted: bool,
+    ///Iterations up to this depth can only be interrupted by the stop handle
+    min_depth: u8,
+    ordering: MoveOrdering,
+    ///Plies the line currently being searched has been extended by
+    line_extensions: u8,
+}
+
+impl<'a> SearchContext<'a> {
+    fn visit_node(&mut self) -> Option<()> {
+        self.nodes += 1;
+        if self.limits.is_stopped()
+            || self.can_be_interrupted
+                && (self.stop.load(Ordering::Relaxed)
+                || self.limits.is_node_limit_reached(self.nodes)
+                || self.is_hard_limit_reached())
+        {
+            None
+        } else {
+            Some(())
+        }
+    }
+
+    ///A draw is worth less than nothing to the player the search is for when they think they are
+    ///stronger, and worth as much more to their opponent
+    fn draw_value(&self, game: &Game) -> i32 {
+        if game.unwrap().is_first_player_turn == self.root_is_first_player {
+            -self.contempt
+        } else {
+            self.contempt
+        }
+    }
+
+    ///Plies to search `possible_move`, played from `game`, deeper than its siblings
+    fn extension(
+        &self,
+        game: &Game,
+        possible_move: &Move,
+        move_result: &Game,
+        previous_move: Option<&Move>,
+    ) -> u8 {
+        self.options.extensions.extension(
+            possible_move,
+            move_result,
+            previous_move,
+            game.unwrap().is_first_player_turn,
+            self.line_extensions,
+        )
+    }
+
+    fn reached_ply(&mut self, ply: u8) {
+        self.seldepth = self.seldepth.max(ply);
+    }
+
+    fn is_soft_limit_reached(&self) -> bool {
+        self.time_spent()
+            .zip(self.time_budget)
+            .is_some_and(|(time_spent, budget)| time_spent >= budget.soft)
+    }
+
+    fn is_hard_limit_reached(&self) -> bool {
+        self.time_spent()
+            .zip(self.time_budget)
+            .is_some_and(|(time_spent, budget)| time_spent >= budget.hard)
+    }
+
+    ///Time counted against the time limits, none while pondering
+    fn time_spent(&self) -> Option<Duration> {
+        self.limits
+            .clock_start(self.start)
+            .map(|clock_start| clock_start.elapsed())
+    }
+}
+
+///Returns the best move and its value for the player to move, or `None` if the search was
+///interrupted before every move could be searched. If the value is outside the window it is only
+///a bound, and the search should be repeated with a wider window.
+fn search_to_depth(
+    game: &mut Game,
+    depth: u8,
+    context: &mut SearchContext,
+    mut alpha: i32,
+    beta: i32,
+    excluded_moves: &[Move],
+    observer: &mut dyn SearchObserver,
+) -> Option<(Move, i32)> {
+    let hash = game.unwrap().zobrist_hash();
+    let hash_move = context
+        .table
+        .probe(hash)
+        .and_then(|entry| entry.best_move.clone());
+    let original_alpha = alpha;
+    let mut best: Option<(Move, i32)> = None;
+
+    for (index, possible_move) in order(game, context, hash_move, None, 0)
+        .into_iter()
+        .filter(|possible_move| !excluded_moves.contains(possible_move))
+        .enumerate()
+    {
+        observer.on_root_move(depth, &possible_move, index + 1);
+        context.visit_node()?;
+        let mut move_result = game.make_move(&possible_move);
+        let extension = context.extension(game, &possible_move, &move_result, None);
+        context.line_extensions += extension;
+        let value = search_move(
             &mut move_result,
-            0,
-            !is_first_player,
-            &heuristics,
-            i32::MIN,
-            i32::MAX,
+            &possible_move,
+            context,
+            MoveSearch {
+                depth: depth - 1 + extension,
+                reduction: 0,
+                alpha,
+                beta,
+                ply: 1,
+                is_first_move: index == 0,
+            },
         );
-        #[cfg(debug_assertions)]
-        println!("Possible move OUTCOME: {possible_move}, {value}");
+        context.line_extensions -= extension;
+        let value = value?;
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_value)| value > *best_value)
+        {
+            best = Some((possible_move, value));
+            alpha = i32::max(alpha, value);
+            if excluded_moves.is_empty() {
+                context.root_best = best.clone();
+            }
+        }
+        if alpha >= beta {
+            break;
+        }
+    }
 
-        results.push(PossibleMove {
-            value,
-            possible_move,
-            is_maximising: is_first_player,
-        });
+    let (best_move, best_value) = best.expect("There is always a move that isn't excluded");
+    //With moves excluded this isn't the real value of the position
+    if excluded_moves.is_empty() {
+        let bound = if best_value <= original_alpha {
+            Bound::Upper
+        } else if best_value >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        context
+            .table
+            .store(hash, depth, bound, best_value, Some(best_move.clone()), 0);
     }
+    Some((best_move, best_value))
+}
 
-    Evaluation {
-        best_move: results.pop().unwrap().possible_move.generate_san(),
+///Finds the best `multi_pv` root moves, best first. Each is searched with every better move
+///excluded, so its value is exact rather than a bound from the first move's search.
+fn search_lines(
+    game: &mut Game,
+    depth: u8,
+    context: &mut SearchContext,
+    previous_value: Option<i32>,
+    observer: &mut dyn SearchObserver,
+) -> Option<Vec<(Move, i32)>> {
+    let line_count = context
+        .options
+        .multi_pv
+        .clamp(1, game.unwrap().possible_moves.len());
+    context.root_best = None;
+    let mut lines = vec![search_with_aspiration_window(
+        game,
+        depth,
+        context,
+        previous_value,
+        observer,
+    )?];
+    while lines.len() < line_count {
+        let excluded_moves: Vec<Move> = lines
+            .iter()
+            .map(|(possible_move, _)| possible_move.clone())
+            .collect();
+        lines.push(search_to_depth(
+            game,
+            depth,
+            context,
+            -INFINITY,
+            INFINITY,
+            &excluded_moves,
+            observer,
+        )?);
     }
+    Some(lines)
 }
 
-fn minmax(
+///Searches a narrow window around the previous iteration's value, as the value rarely changes
+///much between iterations and a narrow window cuts off more. If the value falls outside the
+///window, the search is repeated with the window widened on that side, until after a few failures
+///the full window is searched instead.
+fn search_with_aspiration_window(
     game: &mut Game,
     depth: u8,
-    is_maximising: bool,
-    heuristics: &Heuristics,
+    context: &mut SearchContext,
+    previous_value: Option<i32>,
+    observer: &mut dyn SearchObserver,
+) -> Option<(Move, i32)> {
+    let previous_value = match previous_value {
+        Some(value) if context.options.aspiration_windows && value.abs() < MATE_THRESHOLD => value,
+        _ => return search_to_depth(game, depth, context, -INFINITY, INFINITY, &[], observer),
+    };
+    let mut delta = (context.heuristics.pawn_value() / 2).max(1);
+    let mut alpha = previous_value - delta;
+    let mut beta = previous_value + delta;
+    let mut failures = 0;
+
+    loop {
+        let (best_move, value) = search_to_depth(game, depth, context, alpha, beta, &[], observer)?;
+        delta = delta.saturating_mul(2);
+        if value <= alpha && alpha > -INFINITY {
+            alpha = i32::max(value.saturating_sub(delta), -INFINITY);
+        } else if value >= beta && beta < INFINITY {
+            beta = i32::min(value.saturating_add(delta), INFINITY);
+        } else {
+            return Some((best_move, value));
+        }
+        //The value is swinging too much for a window to help, so settle it with a full search
+        failures += 1;
+        if failures >= ASPIRATION_MAX_FAILURES {
+            alpha = -INFINITY;
+            beta = INFINITY;
+        }
+    }
+}
+
+///How the position after a move is searched, from the point of view of the player who made it
+struct MoveSearch {
+    depth: u8,
+    ///Plies taken off `depth` for a first search, which is repeated at full depth if it beats alpha
+    reduction: u8,
+    alpha: i32,
+    beta: i32,
+    ply: u8,
+    ///The first move is expected to be the principal variation, so gets the full window at once
+    is_first_move: bool,
+}
+
+///Searches the position after a move, for the player who made it. After the first move the
+///principal variation is expected to be known already, so later moves only need to be shown to be
+///worse with a zero-width window around alpha. Moves that aren't are searched again with the full
+///window, as are reduced moves that turn out to beat alpha.
+fn search_move(
+    move_result: &mut Game,
+    possible_move: &Move,
+    context: &mut SearchContext,
+    move_search: MoveSearch,
+) -> Option<i32> {
+    let MoveSearch {
+        depth,
+        reduction,
+        alpha,
+        beta,
+        ply,
+        is_first_move,
+    } = move_search;
+    if reduction > 0 {
+        let value = -negamax(
+            move_result,
+            depth.saturating_sub(reduction),
+            context,
+            -alpha - 1,
+            -alpha,
+            ply,
+            Some(possible_move),
+        )?;
+        if value <= alpha {
+            return Some(value);
+        }
+    }
+
+    if context.options.principal_variation_search && !is_first_move {
+        let value = -negamax(
+            move_result,
+            depth,
+            context,
+            -alpha - 1,
+            -alpha,
+            ply,
+            Some(possible_move),
+        )?;
+        if value <= alpha || value >= beta {
+            return Some(value);
+        }
+    }
+
+    Some(-negamax(
+        move_result,
+        depth,
+        context,
+        -beta,
+        -alpha,
+        ply,
+        Some(possible_move),
+    )?)
+}
+
+///Follows the best moves stored in the transposition table from the position after `best_move`
+fn principal_variation(
+    game: &Game,
+    best_move: Move,
+    depth: u8,
+    table: &TranspositionTable,
+) -> Vec<Move> {
+    let mut current = game.make_move(&best_move);
+    let mut principal_variation = vec![best_move];
+    while principal_variation.len() < depth as usize && matches!(current, Game::Ongoing { .. }) {
+        let state = current.unwrap();
+        let next_move = table
+            .probe(state.zobrist_hash())
+            .and_then(|entry| entry.best_move.clone())
+            .filter(|next_move| state.possible_moves.contains(next_move));
+        match next_move {
+            Some(next_move) => {
+                current = current.make_move(&next_move);
+                principal_variation.push(next_move);
+            }
+            None => break,
+        }
+    }
+    principal_variation
+}
+
+///Scores are always from the point of view of the player to move, so the score of a move is the
+///negation of the score the opponent gets for the position it leads to
+fn negamax(
+    game: &mut Game,
+    depth: u8,
+    context: &mut SearchContext,
     mut alpha: i32,
     mut beta: i32,
-) -> i32 {
-    let is_ongoing = !matches!(game, Game::Ongoing { .. });
-    if depth == MAX_DEPTH || is_ongoing {
-        heuristics.evaluate(game.unwrap_mut())
-    } else {
-        let mut best_value = if is_maximising { i32::MIN } else { i32::MAX };
-        for possible_move in game.unwrap().possible_moves.iter() {
-            let mut move_result = game.make_move(possible_move);
-            let value = minmax(
-                &mut move_result,
-                depth + 1,
-                !is_maximising,
-                heuristics,
+    ply: u8,
+    previous_move: Option<&Move>,
+) -> Option<i32> {
+    context.reached_ply(ply);
+    if let Some(value) = terminal_value(game, context, ply) {
+        return Some(value);
+    }
+    //Repeating a position once is enough to show that neither player can avoid repeating it again
+    let state = game.unwrap();
+    if state.is_repetition() || state.is_fifty_move_draw() {
+        return Some(context.draw_value(game));
+    }
+
+    //No line from here can beat a mate that has already been found nearer the root
+    alpha = i32::max(alpha, -MATE + ply as i32);
+    beta = i32::min(beta, MATE - ply as i32 - 1);
+    if alpha >= beta {
+        return Some(alpha);
+    }
+
+    let hash = game.unwrap().zobrist_hash();
+    let (original_alpha, original_beta) = (alpha, beta);
+    let mut hash_move = None;
+    if let Some(entry) = context.table.probe(hash) {
+        hash_move = entry.best_move.clone();
+        if entry.depth >= depth {
+            let score = entry.score(ply);
+            match entry.bound {
+                Bound::Exact => return Some(score),
+                Bound::Lower => alpha = i32::max(alpha, score),
+                Bound::Upper => beta = i32::min(beta, score),
+            }
+            if alpha >= beta {
+                return Some(score);
+            }
+        }
+    }
+
+    if depth == 0 {
+        let include_checks = context.options.quiescence_checks;
+        return quiescence(game, context, alpha, beta, ply, include_checks);
+    }
+
+    let is_in_check = game.unwrap().is_check(game.unwrap().is_first_player_turn);
+    if context.options.null_move_pruning
+        && can_prune_null_move(game, depth, beta, is_in_check, previous_move)
+    {
+        context.visit_node()?;
+        let mut move_result = game.make_null_move();
+        let reduced_depth = (depth - 1).saturating_sub(NULL_MOVE_REDUCTION);
+        let value = -negamax(
+            &mut move_result,
+            reduced_depth,
+            context,
+            -beta,
+            -beta + 1,
+            ply + 1,
+            None,
+        )?;
+        if value >= beta {
+            return Some(beta);
+        }
+    }
+
+    let mut best_value = -INFINITY;
+    let mut best_move = None;
+    let ordered_moves = order(game, context, hash_move, previous_move, ply);
+    for (index, possible_move) in ordered_moves.into_iter().enumerate() {
+        context.visit_node()?;
+        let mut move_result = game.make_move(&possible_move);
+        let extension = context.extension(game, &possible_move, &move_result, previous_move);
+        let reduction = if context.options.late_move_reductions
+            && extension == 0
+            && can_reduce_late_move(&possible_move, &move_result, index, depth, is_in_check)
+        {
+            LATE_MOVE_REDUCTION
+        } else {
+            0
+        };
+        context.line_extensions += extension;
+        let value = search_move(
+            &mut move_result,
+            &possible_move,
+            context,
+            MoveSearch {
+                depth: depth - 1 + extension,
+                reduction,
                 alpha,
                 beta,
-            );
-            if (is_maximising && value > best_value) || (!is_maximising && value < best_value) {
-                best_value = value;
+                ply: ply + 1,
+                is_first_move: index == 0,
+            },
+        );
+        context.line_extensions -= extension;
+        let value = value?;
+        if value > best_value {
+            best_value = value;
+            best_move = Some(possible_move.clone());
+        }
+        alpha = i32::max(value, alpha);
+        if alpha >= beta {
+            if context.options.move_ordering {
+                context.ordering.record_cutoff(
+                    game.unwrap(),
+                    &possible_move,
+                    previous_move,
+                    ply,
+                    depth,
+                );
             }
+            break;
+        }
+    }
 
-            if is_maximising {
-                if value >= beta {
-                    break;
-                }
-                alpha = i32::max(value, alpha);
+    let bound = if best_value <= original_alpha {
+        Bound::Upper
+    } else if best_value >= original_beta {
+        Bound::Lower
+    } else {
+        Bound::Exact
+    };
+    context
+        .table
+        .store(hash, depth, bound, best_value, best_move, ply);
+    Some(best_value)
+}
+
+///Passing the turn is normally the worst thing a player could do, so if the opponent still can't
+///stop a cutoff after it, a real move would cut off too. This doesn't hold when every move makes
+///things worse (zugzwang), which is common when in check or when only pawns are left.
+fn can_prune_null_move(
+    game: &Game,
+    depth: u8,
+    beta: i32,
+    is_in_check: bool,
+    previous_move: Option<&Move>,
+) -> bool {
+    let state = game.unwrap();
+    depth >= NULL_MOVE_MIN_DEPTH
+        && !is_in_check
+        //Two null moves in a row would just search the same position shallower
+        && previous_move.is_some()
+        && beta.abs() < MATE_THRESHOLD
+        && state.has_non_pawn_material(state.is_first_player_turn)
+        //Captures are compulsory in antichess, so passing is never the worst move
+        && state.variant != Variant::Antichess
+}
+
+///Once the best moves have been searched, the rest are unlikely to be better. Quiet ones are
+///searched shallower, and only searched fully if they beat alpha anyway.
+fn can_reduce_late_move(
+    possible_move: &Move,
+    move_result: &Game,
+    index: usize,
+    depth: u8,
+    is_in_check: bool,
+) -> bool {
+    index >= LATE_MOVE_REDUCTION_AFTER_MOVES
+        && depth >= LATE_MOVE_REDUCTION_MIN_DEPTH
+        && !is_in_check
+        && !possible_move.is_capture()
+        && !matches!(possible_move, Move::PawnPromotion(..))
+        && matches!(move_result, Game::Ongoing { .. })
+        && !move_result
+            .unwrap()
+            .is_check(move_result.unwrap().is_first_player_turn)
+}
+
+///Finished games are scored by the search rather than the heuristics, so that a checkmate found
+///nearer the root is always preferred
+fn terminal_value(game: &Game, context: &SearchContext, ply: u8) -> Option<i32> {
+    match game {
+        Game::Win {
+            is_first_player_win,
+            state,
+        } => {
+            let mate = MATE - ply as i32;
+            Some(if *is_first_player_win == state.is_first_player_turn {
+                mate
             } else {
-                if value <= alpha {
-                    break;
-                }
-                beta = i32::min(value, beta);
-            }
+                -mate
+            })
         }
-        best_value
+        Game::Draw { .. } => Some(context.draw_value(game)),
+        Game::Ongoing { .. } | Game::IllegalMove { .. } => None,
+    }
+}
+
+///The heuristics score positions for the first player, this scores them for the player to move
+fn evaluate(game: &mut Game, context: &SearchContext) -> i32 {
+    let is_first_player_turn = game.unwrap().is_first_player_turn;
+    let value = context.heuristics.evaluate(game.unwrap_mut());
+    if is_first_player_turn {
+        value
+    } else {
+        -value
+    }
+}
+
+fn order(
+    game: &Game,
+    context: &SearchContext,
+    hash_move: Option<Move>,
+    previous_move: Option<&Move>,
+    ply: u8,
+) -> Vec<Move> {
+    let state = game.unwrap();
+    if context.options.move_ordering {
+        context.ordering.order(
+            state,
+            &state.possible_moves,
+            hash_move.as_ref(),
+            previous_move,
+            ply,
+        )
+    } else {
+        order_moves(&state.possible_moves, hash_move)
+    }
+}
+
+///Searches the best move found by an earlier search first, as it is the most likely to cause a
+///cutoff, and everything else in generation order. Used when move ordering is switched off.
+fn order_moves(possible_moves: &[Move], hash_move: Option<Move>) -> Vec<Move> {
+    let mut ordered_moves = possible_moves.to_vec();
+    if let Some(index) = hash_move.and_then(|hash_move| {
+        ordered_moves
+            .iter()
+            .position(|possible_move| *possible_move == hash_move)
+    }) {
+        ordered_moves[..=index].rotate_right(1);
     }
+    ordered_moves
 }
@@ -0,0 +1,349 @@
+use crate::heuristics::material::material_for;
+use crate::moves::Move;
+use crate::state::coordinates::Coordinate;
+use crate::state::tile::Tile;
+use crate::state::GameState;
+
+const HASH_MOVE_SCORE: i32 = 1_000_000;
+const CAPTURE_SCORE: i32 = 500_000;
+const PROMOTION_SCORE: i32 = 400_000;
+const KILLER_MOVE_SCORE: i32 = 300_000;
+const COUNTER_MOVE_SCORE: i32 = 200_000;
+///History scores are halved once any of them passes this, so they never outrank a counter move
+const MAX_HISTORY_SCORE: i32 = 100_000;
+const KILLER_MOVES_PER_PLY: usize = 2;
+///0x88 coordinates fit in a single byte
+const SQUARES: usize = 128;
+
+///Decides which order to search moves in, so the ones most likely to cause an alpha-beta cutoff
+///are searched first: the hash move, then captures of the most valuable victim by the least
+///valuable attacker (MVV-LVA), then promotions, then quiet moves that caused cutoffs elsewhere in
+///the tree. Quiet moves are remembered as killer moves for the ply they cut off at, as the counter
+///to the move before them, and in a history table that favours moves that keep cutting off.
+pub struct MoveOrdering {
+    killer_moves: Vec<[Option<Move>; KILLER_MOVES_PER_PLY]>,
+    counter_moves: Vec<Option<Move>>,
+    history: Vec<i32>,
+}
+
+impl MoveOrdering {
+    pub fn new() -> Self {
+        MoveOrdering {
+            killer_moves: vec![],
+            counter_moves: vec![None; SQUARES * SQUARES],
+            history: vec![0; 2 * SQUARES * SQUARES],
+        }
+    }
+
+    ///Returns the moves best first, moves that score the same stay in generation order
+    pub fn order(
+        &self,
+        state: &GameState,
+        possible_moves: &[Move],
+        hash_move: Option<&Move>,
+        previous_move: Option<&Move>,
+        ply: u8,
+    ) -> Vec<Move> {
+        let mut scored_moves: Vec<(i32, &Move)> = possible_moves
+            .iter()
+            .map(|possible_move| {
+                let score = if Some(possible_move) == hash_move {
+                    HASH_MOVE_SCORE
+                } else {
+                    self.score(state, possible_move, previous_move, ply)
+                };
+                (score, possible_move)
+            })
+            .collect();
+        scored_moves.sort_by(|(a, _), (b, _)| b.cmp(a));
+        scored_moves
+            .into_iter()
+            .map(|(_, possible_move)| possible_move.clone())
+            .collect()
+    }
+
+    ///Remember a quiet move that caused a beta cutoff
+    pub fn record_cutoff(
+        &mut self,
+        state: &GameState,
+        cutoff_move: &Move,
+        previous_move: Option<&Move>,
+        ply: u8,
+        depth: u8,
+    ) {
+        if is_noisy(cutoff_move) {
+            return;
+        }
+        let is_first_player = state.is_first_player_turn;
+
+        let ply = ply as usize;
+        if self.killer_moves.len() <= ply {
+            self.killer_moves.resize(ply + 1, [None, None]);
+        }
+        let killers = &mut self.killer_moves[ply];
+        if killers[0].as_ref() != Some(cutoff_move) {
+            killers[1] = killers[0].take();
+            killers[0] = Some(cutoff_move.clone());
+        }
+
+        if let Some(previous_move) = previous_move {
+            let index = square_index(previous_move, !is_first_player);
+            self.counter_moves[index] = Some(cutoff_move.clone());
+        }
+
+        let index = history_index(cutoff_move, is_first_player);
+        self.history[index] += depth as i32 * depth as i32;
+        if self.history[index] > MAX_HISTORY_SCORE {
+            self.history.iter_mut().for_each(|score| *score /= 2);
+        }
+    }
+
+    fn score(
+        &self,
+        state: &GameState,
+        possible_move: &Move,
+        previous_move: Option<&Move>,
+        ply: u8,
+    ) -> i32 {
+        if let Some(score) = score_noisy(state, possible_move) {
+            return score;
+        }
+
+        if let Some(killers) = self.killer_moves.get(ply as usize) {
+            if let Some(index) = killers
+                .iter()
+                .position(|killer| killer.as_ref() == Some(possible_move))
+            {
+                return KILLER_MOVE_SCORE - index as i32;
+            }
+        }
+
+        let is_first_player = state.is_first_player_turn;
+        if previous_move.is_some_and(|previous_move| {
+            self.counter_moves[square_index(previous_move, !is_first_player)].as_ref()
+                == Some(possible_move)
+        }) {
+            return COUNTER_MOVE_SCORE;
+        }
+
+        self.history[history_index(possible_move, is_first_player)]
+    }
+}
+
+impl Default for MoveOrdering {
+    fn default() -> Self {
+        MoveOrdering::new()
+    }
+}
+
+///Orders captures and promotions for the quiescence search, which has no quiet moves to order
+pub fn order_noisy_moves(state: &GameState, possible_moves: &[Move]) -> Vec<Move> {
+    let mut scored_moves: Vec<(i32, &Move)> = possible_moves
+        .iter()
+        .map(|possible_move| {
+            (
+                score_noisy(state, possible_move).unwrap_or(0),
+                possible_move,
+            )
+        })
+        .collect();
+    scored_moves.sort_by(|(a, _), (b, _)| b.cmp(a));
+    scored_moves
+        .into_iter()
+        .map(|(_, possible_move)| possible_move.clone())
+        .collect()
+}
+
+fn is_noisy(possible_move: &Move) -> bool {
+    possible_move.is_capture()
+        || matches!(
+            possible_move,
+            Move::PawnPromotion(..) | Move::PawnAttackPromotion(..)
+        )
+}
+
+fn score_noisy(state: &GameState, possible_move: &Move) -> Option<i32> {
+    match possible_move {
+        Move::AttackMove(_, to, attacker) => Some(
+            CAPTURE_SCORE
+                + most_valuable_victim_least_valuable_attacker(state.board[*to], *attacker),
+        ),
+        Move::PawnAttackMove(_, to) => Some(
+            CAPTURE_SCORE
+                + most_valuable_victim_least_valuable_attacker(state.board[*to], Tile::FIRST_PAWN),
+        ),
+        Move::EnPassant(..) => Some(
+            CAPTURE_SCORE
+                + most_valuable_victim_least_valuable_attacker(Tile::FIRST_PAWN, Tile::FIRST_PAWN),
+        ),
+        Move::PawnAttackPromotion(_, to, promote_to) => Some(
+            CAPTURE_SCORE
+                + most_valuable_victim_least_valuable_attacker(state.board[*to], Tile::FIRST_PAWN)
+                + material_for(*promote_to),
+        ),
+        Move::PawnPromotion(_, promote_to) => Some(PROMOTION_SCORE + material_for(*promote_to)),
+        _ => None,
+    }
+}
+
+fn most_valuable_victim_least_valuable_attacker(victim: Tile, attacker: Tile) -> i32 {
+    material_for(victim) * 100 - material_for(attacker)
+}
+
+fn history_index(possible_move: &Move, is_first_player: bool) -> usize {
+    let side = if is_first_player { 0 } else { 1 };
+    side * SQUARES * SQUARES + square_index(possible_move, is_first_player)
+}
+
+///Identifies a move by where it starts and ends. Promotions and drops have no starting square, so
+///they use the target square for both.
+fn square_index(possible_move: &Move, is_first_player: bool) -> usize {
+    let (from, to) = match possible_move {
+        Move::RegularMove(from, to, _)
+        | Move::AttackMove(from, to, _)
+        | Move::PawnMove(from, to)
+        | Move::PawnAttackMove(from, to)
+        | Move::PawnAttackPromotion(from, to, _)
+        | Move::EnPassant(from, to) => (*from, *to),
+        Move::PawnPromotion(to, _) | Move::Drop(_, to) => (*to, *to),
+        Move::Castle(is_kingside) => castle_squares(*is_kingside, is_first_player),
+    };
+    from.as_usize() * SQUARES + to.as_usize()
+}
+
+fn castle_squares(is_kingside: bool, is_first_player: bool) -> (Coordinate, Coordinate) {
+    match (is_kingside, is_first_player) {
+        (true, true) => (Coordinate::E1, Coordinate::G1),
+        (false, true) => (Coordinate::E1, Coordinate::C1),
+        (true, false) => (Coordinate::E8, Coordinate::G8),
+        (false, false) => (Coordinate::E8, Coordinate::C8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ordered_sans(ordered_moves: Vec<Move>) -> Vec<String> {
+        ordered_moves
+            .iter()
+            .map(|possible_move| possible_move.generate_san())
+            .collect()
+    }
+
+    #[test]
+    fn hash_move_is_first() {
+        let state = GameState::from_fen("3k4/8/3q4/8/8/3Q4/8/3K4 w - - 0 1");
+        let ordering = MoveOrdering::new();
+        let hash_move = Move::RegularMove(Coordinate::D1, Coordinate::C1, Tile::FIRST_KING);
+
+        let result = ordering.order(&state, &state.possible_moves, Some(&hash_move), None, 0);
+
+        assert_eq!(hash_move, result[0]);
+    }
+
+    #[test]
+    fn captures_most_valuable_victim_first() {
+        let state = GameState::from_fen("3k4/8/1r1q4/8/8/1Q1Q4/8/3K4 w - - 0 1");
+        let ordering = MoveOrdering::new();
+
+        let result = ordered_sans(ordering.order(&state, &state.possible_moves, None, None, 0));
+
+        assert_eq!(vec!["Qxd6", "Qxb6"], result[..2]);
+    }
+
+    #[test]
+    fn captures_with_least_valuable_attacker_first() {
+        let state = GameState::from_fen("3k4/8/3r4/2P5/8/3Q4/8/3K4 w - - 0 1");
+        let ordering = MoveOrdering::new();
+
+        let result = ordered_sans(ordering.order(&state, &state.possible_moves, None, None, 0));
+
+        assert_eq!(vec!["cxd6", "Qxd6"], result[..2]);
+    }
+
+    #[test]
+    fn promotions_come_after_captures_before_quiet_moves() {
+        let state = GameState::from_fen("3k4/P7/3r4/8/8/3Q4/8/3K4 w - - 0 1");
+        let ordering = MoveOrdering::new();
+
+        let result = ordered_sans(ordering.order(&state, &state.possible_moves, None, None, 0));
+
+        assert_eq!(vec!["Qxd6", "a8=Q", "a8=R"], result[..3]);
+    }
+
+    #[test]
+    fn killer_move_comes_before_other_quiet_moves() {
+        let state = GameState::from_fen("3k4/8/8/8/8/8/8/R2K4 w - - 0 1");
+        let mut ordering = MoveOrdering::new();
+        let killer = Move::RegularMove(Coordinate::A1, Coordinate::A7, Tile::FIRST_ROOK);
+
+        ordering.record_cutoff(&state, &killer, None, 3, 1);
+        let result = ordering.order(&state, &state.possible_moves, None, None, 3);
+
+        assert_eq!(killer, result[0]);
+    }
+
+    #[test]
+    fn killer_move_is_only_used_at_its_own_ply() {
+        let state = GameState::from_fen("3k4/8/8/8/8/8/8/R2K4 w - - 0 1");
+        let mut ordering = MoveOrdering::new();
+        let other_ply_killer = Move::RegularMove(Coordinate::A1, Coordinate::A7, Tile::FIRST_ROOK);
+        let killer = Move::RegularMove(Coordinate::A1, Coordinate::A2, Tile::FIRST_ROOK);
+
+        ordering.record_cutoff(&state, &other_ply_killer, None, 3, 4);
+        ordering.record_cutoff(&state, &killer, None, 2, 1);
+        let result = ordering.order(&state, &state.possible_moves, None, None, 2);
+
+        assert_eq!(vec![killer, other_ply_killer], result[..2]);
+    }
+
+    #[test]
+    fn captures_are_not_remembered_as_killer_moves() {
+        let state = GameState::from_fen("3k4/8/3q4/8/8/3Q4/8/R2K4 w - - 0 1");
+        let mut ordering = MoveOrdering::new();
+        let capture = Move::AttackMove(Coordinate::D3, Coordinate::D6, Tile::FIRST_QUEEN);
+
+        ordering.record_cutoff(&state, &capture, None, 0, 1);
+
+        assert!(ordering.killer_moves.is_empty());
+    }
+
+    #[test]
+    fn counter_move_comes_before_history() {
+        let state = GameState::from_fen("3k4/8/8/8/8/8/8/R2K4 w - - 0 1");
+        let mut ordering = MoveOrdering::new();
+        let previous_move = Move::RegularMove(Coordinate::E8, Coordinate::D8, Tile::SECOND_KING);
+        let counter = Move::RegularMove(Coordinate::A1, Coordinate::A2, Tile::FIRST_ROOK);
+        let other = Move::RegularMove(Coordinate::A1, Coordinate::A7, Tile::FIRST_ROOK);
+
+        ordering.record_cutoff(&state, &other, None, 5, 3);
+        ordering.record_cutoff(&state, &counter, Some(&previous_move), 6, 1);
+        let result = ordering.order(&state, &state.possible_moves, None, Some(&previous_move), 0);
+
+        assert_eq!(vec![counter, other], result[..2]);
+    }
+
+    #[test]
+    fn history_favours_moves_that_cut_off_deeper() {
+        let state = GameState::from_fen("3k4/8/8/8/8/8/8/R2K4 w - - 0 1");
+        let mut ordering = MoveOrdering::new();
+        let shallow = Move::RegularMove(Coordinate::A1, Coordinate::A2, Tile::FIRST_ROOK);
+        let deep = Move::RegularMove(Coordinate::A1, Coordinate::A7, Tile::FIRST_ROOK);
+
+        ordering.record_cutoff(&state, &shallow, None, 5, 1);
+        ordering.record_cutoff(&state, &deep, None, 6, 4);
+        let result = ordering.order(&state, &state.possible_moves, None, None, 0);
+
+        assert_eq!(vec![deep, shallow], result[..2]);
+    }
+
+    #[test]
+    fn quiescence_ordering_puts_best_capture_first() {
+        let state = GameState::from_fen("3k4/8/1r6/3q4/8/1Q6/8/7K w - - 0 1");
+
+        let result = ordered_sans(order_noisy_moves(&state, &state.possible_moves));
+
+        assert_eq!("Qxd5", result[0]);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::game::Game;
+use crate::state::variant::Variant;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///Why a search had no move to return
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoMoveError {
+    ///The player to move is checkmated
+    Checkmate,
+    ///The player to move has no legal moves but isn't in check
+    Stalemate,
+    ///The game has already ended some other way, e.g. by a variant's rules or by repetition
+    GameOver,
+}
+
+impl NoMoveError {
+    ///None while the game can still be played
+    pub(crate) fn from_game(game: &Game) -> Option<NoMoveError> {
+        let state = match game {
+            Game::Ongoing { .. } | Game::IllegalMove { .. } => return None,
+            Game::Draw { state } | Game::Win { state, .. } => state,
+        };
+        let is_out_of_moves =
+            state.possible_moves.is_empty() && state.variant != Variant::Antichess;
+        let is_check = state.is_check(state.is_first_player_turn);
+        Some(match game {
+            Game::Win { .. } if is_out_of_moves && is_check => NoMoveError::Checkmate,
+            Game::Draw { .. } if is_out_of_moves && !is_check => NoMoveError::Stalemate,
+            _ => NoMoveError::GameOver,
+        })
+    }
+}
+
+impl Display for NoMoveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoMoveError::Checkmate => write!(f, "no move: checkmate"),
+            NoMoveError::Stalemate => write!(f, "no move: stalemate"),
+            NoMoveError::GameOver => write!(f, "no move: game over"),
+        }
+    }
+}
+
+impl Error for NoMoveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ongoing_game_has_moves() {
+        let game = Game::new();
+
+        assert_eq!(None, NoMoveError::from_game(&game));
+    }
+
+    #[test]
+    fn checkmated_player_has_no_move() {
+        let game = Game::from_fen("k6R/8/1K6/8/8/8/8/8 b - - 0 1");
+
+        assert_eq!(Some(NoMoveError::Checkmate), NoMoveError::from_game(&game));
+    }
+
+    #[test]
+    fn stalemated_player_has_no_move() {
+        let game = Game::from_fen("k7/8/1QK5/8/8/8/8/8 b - - 0 1");
+
+        assert_eq!(Some(NoMoveError::Stalemate), NoMoveError::from_game(&game));
+    }
+
+    #[test]
+    fn game_won_by_variant_rules_is_over() {
+        let game =
+            Game::from_fen_with_variant("k7/8/8/3K4/8/8/8/8 b - - 0 1", Variant::KingOfTheHill);
+
+        assert_eq!(Some(NoMoveError::GameOver), NoMoveError::from_game(&game));
+    }
+
+    #[test]
+    fn error_describes_why_there_is_no_move() {
+        assert_eq!("no move: stalemate", NoMoveError::Stalemate.to_string());
+    }
+}
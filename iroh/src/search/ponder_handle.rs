@@ -0,0 +1,72 @@
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+
+///Lets a search run on the opponent's time. Until `ponder_hit` is called, the search ignores its
+///time limits. After a ponder hit, the time limits count from the hit, and everything already
+///searched is kept. On a ponder miss, stop the search with its `StopHandle` and discard the result.
+#[derive(Debug, Clone, Default)]
+pub struct PonderHandle {
+    hit_at: Arc<OnceLock<Instant>>,
+}
+
+impl PonderHandle {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    ///The opponent played the expected move, so the search now has to keep to its time limits
+    pub fn ponder_hit(&self) {
+        let _ = self.hit_at.set(Instant::now());
+    }
+
+    pub fn is_pondering(&self) -> bool {
+        self.hit_at.get().is_none()
+    }
+
+    pub(crate) fn hit_at(&self) -> Option<Instant> {
+        self.hit_at.get().copied()
+    }
+}
+
+impl PartialEq for PonderHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.hit_at, &other.hit_at)
+    }
+}
+
+impl Eq for PonderHandle {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_handle_is_pondering() {
+        let handle = PonderHandle::new();
+
+        assert!(handle.is_pondering());
+        assert_eq!(None, handle.hit_at());
+    }
+
+    #[test]
+    fn clones_share_the_ponder_hit() {
+        let handle = PonderHandle::new();
+        let clone = handle.clone();
+
+        clone.ponder_hit();
+
+        assert!(!handle.is_pondering());
+        assert_eq!(handle, clone);
+    }
+
+    #[test]
+    fn only_the_first_ponder_hit_counts() {
+        let handle = PonderHandle::new();
+        handle.ponder_hit();
+        let first_hit = handle.hit_at();
+
+        handle.ponder_hit();
+
+        assert_eq!(first_hit, handle.hit_at());
+    }
+}
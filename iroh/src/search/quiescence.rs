@@ -0,0 +1,104 @@
+use crate::game::Game;
+use crate::moves::Move;
+use crate::search::move_ordering::order_noisy_moves;
+use crate::search::{evaluate, terminal_value, SearchContext, INFINITY};
+use crate::state::tile::Tile;
+use crate::state::GameState;
+
+///Captures worth less than this, on top of the static evaluation, can't raise alpha so are skipped
+const DELTA_MARGIN_IN_PAWNS: i32 = 2;
+
+///Keeps searching captures and promotions past the depth limit until the position is quiet, so
+///the evaluation isn't taken in the middle of an exchange. The side to move may "stand pat" and
+///accept the static evaluation instead of making a capture, unless it is in check.
+pub(super) fn quiescence(
+    game: &mut Game,
+    context: &mut SearchContext,
+    mut alpha: i32,
+    beta: i32,
+    ply: u8,
+    include_checks: bool,
+) -> Option<i32> {
+    context.reached_ply(ply);
+    if let Some(value) = terminal_value(game, context, ply) {
+        return Some(value);
+    }
+
+    let is_in_check = game.unwrap().is_check(game.unwrap().is_first_player_turn);
+    let stand_pat = if is_in_check {
+        None
+    } else {
+        let stand_pat = evaluate(game, context);
+        if stand_pat >= beta {
+            return Some(stand_pat);
+        }
+        alpha = i32::max(stand_pat, alpha);
+        Some(stand_pat)
+    };
+    let delta_margin =
+        context.heuristics.weighted_material(Tile::FIRST_PAWN) * DELTA_MARGIN_IN_PAWNS;
+
+    let possible_moves = &game.unwrap().possible_moves;
+    let ordered_moves = if context.options.move_ordering {
+        order_noisy_moves(game.unwrap(), possible_moves)
+    } else {
+        possible_moves.clone()
+    };
+    let mut best_value = stand_pat.unwrap_or(-INFINITY);
+    for possible_move in ordered_moves.iter() {
+        let is_noisy = is_in_check || possible_move.is_capture() || is_promotion(possible_move);
+        if !is_noisy && !include_checks {
+            continue;
+        }
+        if let Some(stand_pat) = stand_pat {
+            let best_case = material_gain(possible_move, game.unwrap(), context) + delta_margin;
+            if is_noisy && stand_pat + best_case <= alpha {
+                continue;
+            }
+        }
+
+        let mut move_result = game.make_move(possible_move);
+        if !is_noisy && !gives_check(&move_result) {
+            continue;
+        }
+        context.visit_node()?;
+        let value = -quiescence(&mut move_result, context, -beta, -alpha, ply + 1, false)?;
+        best_value = i32::max(value, best_value);
+        alpha = i32::max(value, alpha);
+        if alpha >= beta {
+            break;
+        }
+    }
+    Some(best_value)
+}
+
+fn is_promotion(possible_move: &Move) -> bool {
+    matches!(
+        possible_move,
+        Move::PawnPromotion(..) | Move::PawnAttackPromotion(..)
+    )
+}
+
+fn gives_check(game: &Game) -> bool {
+    let state = game.unwrap();
+    state.is_check(state.is_first_player_turn)
+}
+
+fn material_gain(possible_move: &Move, state: &GameState, context: &SearchContext) -> i32 {
+    match possible_move {
+        Move::AttackMove(_, to, _) | Move::PawnAttackMove(_, to) => {
+            context.heuristics.weighted_material(state.board[*to])
+        }
+        Move::EnPassant(..) => context.heuristics.weighted_material(Tile::FIRST_PAWN),
+        Move::PawnPromotion(_, tile) => {
+            context.heuristics.weighted_material(*tile)
+                - context.heuristics.weighted_material(Tile::FIRST_PAWN)
+        }
+        Move::PawnAttackPromotion(_, to, tile) => {
+            context.heuristics.weighted_material(state.board[*to])
+                + context.heuristics.weighted_material(*tile)
+                - context.heuristics.weighted_material(Tile::FIRST_PAWN)
+        }
+        _ => 0,
+    }
+}
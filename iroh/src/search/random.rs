@@ -0,0 +1,54 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+///The SplitMix64 generator, which is plenty for choosing moves
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    ///Seeded from the clock, so it differs every run
+    pub(crate) fn from_time() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+        SplitMix64::new(seed)
+    }
+
+    pub(crate) fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    ///Uniform below `bound`, which must not be zero
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_numbers() {
+        let mut first = SplitMix64::new(42);
+        let mut second = SplitMix64::new(42);
+
+        assert_eq!(first.next(), second.next());
+        assert_eq!(first.next(), second.next());
+    }
+
+    #[test]
+    fn numbers_are_below_bound() {
+        let mut random = SplitMix64::new(42);
+
+        assert!((0..100).all(|_| random.below(3) < 3));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::heuristics::weightings::Weightings;
+use crate::heuristics::Heuristics;
+use crate::search::search_options::SearchOptions;
+
+///What a `Searcher` is built from: how positions are valued, and which parts of the search are
+///used. Defaults to the standard heuristics and options.
+#[derive(Default)]
+pub struct SearchConfig {
+    pub heuristics: Heuristics,
+    pub options: SearchOptions,
+}
+
+impl SearchConfig {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_heuristics(mut self, heuristics: Heuristics) -> Self {
+        self.heuristics = heuristics;
+        self
+    }
+
+    ///The standard heuristics, weighted differently
+    pub fn with_weightings(self, weightings: Weightings) -> Self {
+        self.with_heuristics(Heuristics::with_weighting(weightings))
+    }
+
+    pub fn with_options(mut self, options: SearchOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::heuristics::HeuristicType;
+    use crate::state::tile::Tile;
+
+    #[test]
+    fn default_config_uses_default_options() {
+        let result = SearchConfig::new();
+
+        assert_eq!(SearchOptions::new(), result.options);
+    }
+
+    #[test]
+    fn weightings_are_used_by_the_heuristics() {
+        let weightings = Weightings::new().push(HeuristicType::Material, 22.0);
+
+        let result = SearchConfig::new().with_weightings(weightings);
+
+        assert_eq!(22, result.heuristics.weighted_material(Tile::FIRST_PAWN));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::search::ponder_handle::PonderHandle;
+use crate::search::stop_handle::StopHandle;
+use crate::search::time_management::{TimeBudget, TimeControl};
+use std::time::{Duration, Instant};
+
+const DEFAULT_MAX_DEPTH: u8 = 3;
+
+///Bounds on how long a search may run. Depth is measured in plies, so a depth of 1 only looks
+///at the moves available right now.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchLimits {
+    pub max_depth: u8,
+    pub max_nodes: Option<u64>,
+    pub move_time: Option<Duration>,
+    pub time_control: Option<TimeControl>,
+    ///Stops the search as soon as it is triggered, even during the first iteration
+    pub stop_handle: Option<StopHandle>,
+    ///Searches without time limits until a ponder hit
+    pub ponder_handle: Option<PonderHandle>,
+}
+
+impl SearchLimits {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_max_depth(mut self, max_depth: u8) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn with_max_nodes(mut self, max_nodes: u64) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    pub fn with_move_time(mut self, move_time: Duration) -> Self {
+        self.move_time = Some(move_time);
+        self
+    }
+
+    pub fn with_time_control(mut self, time_control: TimeControl) -> Self {
+        self.time_control = Some(time_control);
+        self
+    }
+
+    pub fn with_stop_handle(mut self, stop_handle: StopHandle) -> Self {
+        self.stop_handle = Some(stop_handle);
+        self
+    }
+
+    pub fn with_ponder_handle(mut self, ponder_handle: PonderHandle) -> Self {
+        self.ponder_handle = Some(ponder_handle);
+        self
+    }
+
+    ///When time limits start counting for a search that started at `start`, none while pondering
+    pub(crate) fn clock_start(&self, start: Instant) -> Option<Instant> {
+        match &self.ponder_handle {
+            Some(ponder_handle) => ponder_handle.hit_at(),
+            None => Some(start),
+        }
+    }
+
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.stop_handle
+            .as_ref()
+            .is_some_and(|stop_handle| stop_handle.is_stopped())
+    }
+
+    pub(crate) fn is_node_limit_reached(&self, nodes: u64) -> bool {
+        self.max_nodes.is_some_and(|max_nodes| nodes >= max_nodes)
+    }
+
+    ///A fixed move time is used as both the soft and hard limit. If there is also a time control,
+    ///whichever is shorter wins.
+    pub(crate) fn time_budget(&self, is_first_player: bool) -> Option<TimeBudget> {
+        let fixed = self.move_time.map(|move_time| TimeBudget {
+            soft: move_time,
+            hard: move_time,
+        });
+        let allocated = self
+            .time_control
+            .map(|time_control| time_control.allocate(is_first_player));
+
+        match (fixed, allocated) {
+            (Some(fixed), Some(allocated)) => Some(TimeBudget {
+                soft: fixed.soft.min(allocated.soft),
+                hard: fixed.hard.min(allocated.hard),
+            }),
+            (fixed, allocated) => fixed.or(allocated),
+        }
+    }
+}
+
+impl Default for SearchLimits {
+    fn default() -> Self {
+        SearchLimits {
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_nodes: None,
+            move_time: None,
+            time_control: None,
+            stop_handle: None,
+            ponder_handle: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_limits_only_bound_depth() {
+        let result = SearchLimits::new();
+
+        assert_eq!(DEFAULT_MAX_DEPTH, result.max_depth);
+        assert_eq!(None, result.max_nodes);
+        assert_eq!(None, result.move_time);
+    }
+
+    #[test]
+    fn limits_can_be_chained() {
+        let result = SearchLimits::new()
+            .with_max_depth(5)
+            .with_max_nodes(1000)
+            .with_move_time(Duration::from_millis(50));
+
+        assert_eq!(5, result.max_depth);
+        assert_eq!(Some(1000), result.max_nodes);
+        assert_eq!(Some(Duration::from_millis(50)), result.move_time);
+    }
+
+    #[test]
+    fn given_no_node_limit_is_never_reached() {
+        let limits = SearchLimits::new();
+
+        assert!(!limits.is_node_limit_reached(u64::MAX));
+    }
+
+    #[test]
+    fn given_node_limit_is_reached_once_visited() {
+        let limits = SearchLimits::new().with_max_nodes(10);
+
+        assert!(!limits.is_node_limit_reached(9));
+        assert!(limits.is_node_limit_reached(10));
+    }
+
+    #[test]
+    fn given_stop_handle_is_stopped_once_triggered() {
+        let stop_handle = StopHandle::new();
+        let limits = SearchLimits::new().with_stop_handle(stop_handle.clone());
+
+        assert!(!limits.is_stopped());
+        stop_handle.stop();
+        assert!(limits.is_stopped());
+    }
+
+    #[test]
+    fn given_no_ponder_handle_clock_starts_with_search() {
+        let start = Instant::now();
+        let limits = SearchLimits::new();
+
+        assert_eq!(Some(start), limits.clock_start(start));
+    }
+
+    #[test]
+    fn given_ponder_handle_clock_starts_on_ponder_hit() {
+        let start = Instant::now();
+        let ponder_handle = PonderHandle::new();
+        let limits = SearchLimits::new().with_ponder_handle(ponder_handle.clone());
+
+        assert_eq!(None, limits.clock_start(start));
+        ponder_handle.ponder_hit();
+        assert_eq!(ponder_handle.hit_at(), limits.clock_start(start));
+    }
+
+    #[test]
+    fn given_no_time_limits_there_is_no_time_budget() {
+        let limits = SearchLimits::new();
+
+        assert_eq!(None, limits.time_budget(true));
+    }
+
+    #[test]
+    fn given_move_time_it_is_both_soft_and_hard_limit() {
+        let limits = SearchLimits::new().with_move_time(Duration::from_millis(10));
+
+        let result = limits.time_budget(true).unwrap();
+
+        assert_eq!(Duration::from_millis(10), result.soft);
+        assert_eq!(Duration::from_millis(10), result.hard);
+    }
+
+    #[test]
+    fn given_move_time_and_time_control_shortest_limits_win() {
+        let limits = SearchLimits::new()
+            .with_move_time(Duration::from_secs(2))
+            .with_time_control(TimeControl::sudden_death(Duration::from_millis(30_050)));
+
+        let result = limits.time_budget(true).unwrap();
+
+        assert_eq!(Duration::from_secs(1), result.soft);
+        assert_eq!(Duration::from_secs(2), result.hard);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::moves::Move;
+use crate::search::evaluation::Evaluation;
+
+///Receives progress from a running search. Any `FnMut(&Evaluation)` closure can be used to only
+///hear about completed iterations.
+pub trait SearchObserver {
+    ///Called each time a depth has been searched completely, with the best move at that depth
+    fn on_iteration_complete(&mut self, evaluation: &Evaluation);
+
+    ///Called as each root move starts being searched, `move_number` counts from 1
+    fn on_root_move(&mut self, _depth: u8, _possible_move: &Move, _move_number: usize) {}
+}
+
+impl<F: FnMut(&Evaluation)> SearchObserver for F {
+    fn on_iteration_complete(&mut self, evaluation: &Evaluation) {
+        self(evaluation)
+    }
+}
+
+///Lets an observer be lent to a search and inspected once it finishes
+impl SearchObserver for &mut dyn SearchObserver {
+    fn on_iteration_complete(&mut self, evaluation: &Evaluation) {
+        (**self).on_iteration_complete(evaluation)
+    }
+
+    fn on_root_move(&mut self, depth: u8, possible_move: &Move, move_number: usize) {
+        (**self).on_root_move(depth, possible_move, move_number)
+    }
+}
+
+///Ignores all progress
+pub struct NullObserver;
+
+impl SearchObserver for NullObserver {
+    fn on_iteration_complete(&mut self, _: &Evaluation) {}
+}
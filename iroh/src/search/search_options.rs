@@ -0,0 +1,188 @@
+use crate::search::extensions::SearchExtensions;
+use crate::search::skill_level::SkillLevel;
+
+///Switches for the optional parts of the search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchOptions {
+    ///Also search moves that give check at the first ply of the quiescence search
+    pub quiescence_checks: bool,
+    ///Search the moves most likely to cause a cutoff first, rather than in generation order
+    pub move_ordering: bool,
+    ///Skip positions where passing the turn would still cause a cutoff
+    pub null_move_pruning: bool,
+    ///Search quiet moves late in the move order less deeply, unless they turn out to be good
+    pub late_move_reductions: bool,
+    ///Search all but the first move with a zero-width window, only searching fully if they beat it
+    pub principal_variation_search: bool,
+    ///Start each iteration with a narrow window around the value of the last
+    pub aspiration_windows: bool,
+    ///Number of best moves to find, each with its own score and principal variation
+    pub multi_pv: usize,
+    ///Number of threads to search with, sharing one transposition table
+    pub threads: usize,
+    ///Centipawns the engine thinks it is stronger than its opponent by. Positive avoids draws by
+    ///repetition or the fifty-move rule, negative seeks them.
+    pub contempt: i32,
+    ///Plays weaker on purpose, None for full strength
+    pub skill_level: Option<SkillLevel>,
+    ///Forcing moves to search deeper than the rest
+    pub extensions: SearchExtensions,
+}
+
+impl SearchOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_move_ordering(mut self, move_ordering: bool) -> Self {
+        self.move_ordering = move_ordering;
+        self
+    }
+
+    pub fn with_null_move_pruning(mut self, null_move_pruning: bool) -> Self {
+        self.null_move_pruning = null_move_pruning;
+        self
+    }
+
+    pub fn with_late_move_reductions(mut self, late_move_reductions: bool) -> Self {
+        self.late_move_reductions = late_move_reductions;
+        self
+    }
+
+    pub fn with_principal_variation_search(mut self, principal_variation_search: bool) -> Self {
+        self.principal_variation_search = principal_variation_search;
+        self
+    }
+
+    pub fn with_aspiration_windows(mut self, aspiration_windows: bool) -> Self {
+        self.aspiration_windows = aspiration_windows;
+        self
+    }
+
+    pub fn with_multi_pv(mut self, multi_pv: usize) -> Self {
+        self.multi_pv = multi_pv;
+        self
+    }
+
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    pub fn with_contempt(mut self, contempt: i32) -> Self {
+        self.contempt = contempt;
+        self
+    }
+
+    pub fn with_skill_level(mut self, skill_level: SkillLevel) -> Self {
+        self.skill_level = Some(skill_level);
+        self
+    }
+
+    pub fn with_extensions(mut self, extensions: SearchExtensions) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    pub fn with_quiescence_checks(mut self, quiescence_checks: bool) -> Self {
+        self.quiescence_checks = quiescence_checks;
+        self
+    }
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        SearchOptions {
+            quiescence_checks: false,
+            move_ordering: true,
+            null_move_pruning: true,
+            late_move_reductions: true,
+            principal_variation_search: true,
+            aspiration_windows: true,
+            multi_pv: 1,
+            threads: 1,
+            contempt: 0,
+            skill_level: None,
+            extensions: SearchExtensions::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quiescence_checks_are_off_by_default() {
+        let result = SearchOptions::new();
+
+        assert!(!result.quiescence_checks);
+    }
+
+    #[test]
+    fn quiescence_checks_can_be_enabled() {
+        let result = SearchOptions::new().with_quiescence_checks(true);
+
+        assert!(result.quiescence_checks);
+    }
+
+    #[test]
+    fn move_ordering_is_on_by_default() {
+        let result = SearchOptions::new();
+
+        assert!(result.move_ordering);
+    }
+
+    #[test]
+    fn move_ordering_can_be_disabled() {
+        let result = SearchOptions::new().with_move_ordering(false);
+
+        assert!(!result.move_ordering);
+    }
+
+    #[test]
+    fn selective_search_is_on_by_default() {
+        let result = SearchOptions::new();
+
+        assert!(result.null_move_pruning);
+        assert!(result.late_move_reductions);
+        assert!(result.principal_variation_search);
+        assert!(result.aspiration_windows);
+    }
+
+    #[test]
+    fn selective_search_can_be_disabled_individually() {
+        let result = SearchOptions::new().with_null_move_pruning(false);
+
+        assert!(!result.null_move_pruning);
+        assert!(result.late_move_reductions);
+    }
+
+    #[test]
+    fn only_best_move_is_found_by_default() {
+        let result = SearchOptions::new();
+
+        assert_eq!(1, result.multi_pv);
+    }
+
+    #[test]
+    fn search_is_single_threaded_by_default() {
+        let result = SearchOptions::new();
+
+        assert_eq!(1, result.threads);
+    }
+
+    #[test]
+    fn draws_are_worth_nothing_by_default() {
+        let result = SearchOptions::new();
+
+        assert_eq!(0, result.contempt);
+    }
+
+    #[test]
+    fn full_strength_by_default() {
+        let result = SearchOptions::new();
+
+        assert_eq!(None, result.skill_level);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::game::Game;
+use crate::search::evaluation::Evaluation;
+use crate::search::no_move_error::NoMoveError;
+use crate::search::search_config::SearchConfig;
+use crate::search::search_limits::SearchLimits;
+use crate::search::search_observer::{NullObserver, SearchObserver};
+use crate::search::transposition_table::TranspositionTable;
+
+///A way of choosing a move, so different search algorithms can be swapped for each other
+pub trait Searcher {
+    fn search(
+        &mut self,
+        game: &mut Game,
+        limits: &SearchLimits,
+        observer: &mut dyn SearchObserver,
+    ) -> Result<Evaluation, NoMoveError>;
+}
+
+///Plays the game on between two searchers, to compare them, until it is over or `max_plies` have
+///been played
+pub fn play_out(
+    mut game: Game,
+    first_player: &mut dyn Searcher,
+    second_player: &mut dyn Searcher,
+    limits: &SearchLimits,
+    max_plies: u16,
+) -> Game {
+    for _ in 0..max_plies {
+        let result = if game.unwrap().is_first_player_turn {
+            first_player.search(&mut game, limits, &mut NullObserver)
+        } else {
+            second_player.search(&mut game, limits, &mut NullObserver)
+        };
+        match result {
+            Ok(evaluation) => game = game.make_move(&evaluation.principal_variation[0]),
+            Err(_) => break,
+        }
+    }
+    game
+}
+
+///The standard search: iterative deepening alpha-beta, configured by `SearchConfig`. Its
+///transposition table is kept between searches, so call `new_game` when starting another game.
+#[derive(Default)]
+pub struct AlphaBetaSearcher {
+    config: SearchConfig,
+    table: TranspositionTable,
+}
+
+impl AlphaBetaSearcher {
+    pub fn new(config: SearchConfig) -> Self {
+        AlphaBetaSearcher {
+            config,
+            table: TranspositionTable::default(),
+        }
+    }
+
+    pub fn with_table(mut self, table: TranspositionTable) -> Self {
+        self.table = table;
+        self
+    }
+
+    pub fn config(&self) -> &SearchConfig {
+        &self.config
+    }
+
+    ///Forgets positions from earlier games
+    pub fn new_game(&mut self) {
+        self.table.clear();
+    }
+}
+
+impl Searcher for AlphaBetaSearcher {
+    fn search(
+        &mut self,
+        game: &mut Game,
+        limits: &SearchLimits,
+        observer: &mut dyn SearchObserver,
+    ) -> Result<Evaluation, NoMoveError> {
+        super::alpha_beta(
+            game,
+            limits,
+            &self.config.options,
+            &self.config.heuristics,
+            &mut self.table,
+            observer,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::heuristics::Heuristics;
+    use crate::search::evaluation::Score;
+
+    #[test]
+    fn alpha_beta_searcher_finds_best_move() {
+        let mut game = Game::from_fen("3k4/8/3q4/8/8/3Q4/8/3K4 w - - 0 1");
+        let mut searcher = AlphaBetaSearcher::default();
+
+        let result = searcher
+            .search(&mut game, &SearchLimits::new(), &mut NullObserver)
+            .unwrap();
+
+        assert_eq!("Qxd6", result.best_move);
+    }
+
+    #[test]
+    fn injected_heuristics_value_positions() {
+        let mut game = Game::from_fen("3k4/8/3q4/8/8/3Q4/8/3K4 w - - 0 1");
+        let config = SearchConfig::new().with_heuristics(Heuristics::blank());
+        let mut searcher = AlphaBetaSearcher::new(config);
+
+        let result = searcher
+            .search(&mut game, &SearchLimits::new(), &mut NullObserver)
+            .unwrap();
+
+        assert_eq!(Score::Centipawns(0), result.score);
+    }
+
+    #[test]
+    fn table_is_kept_until_new_game() {
+        let mut game = Game::from_fen("3k4/8/3q4/8/8/3Q4/8/3K4 w - - 0 1");
+        let mut searcher = AlphaBetaSearcher::default();
+        searcher
+            .search(&mut game, &SearchLimits::new(), &mut NullObserver)
+            .unwrap();
+        assert!(!searcher.table.is_empty());
+
+        searcher.new_game();
+
+        assert!(searcher.table.is_empty());
+    }
+
+    #[test]
+    fn play_out_stops_when_game_is_over() {
+        let game = Game::from_fen("k7/8/1K6/8/8/8/8/7R w - - 0 1");
+
+        let result = play_out(
+            game,
+            &mut AlphaBetaSearcher::default(),
+            &mut AlphaBetaSearcher::default(),
+            &SearchLimits::new(),
+            10,
+        );
+
+        assert!(matches!(
+            result,
+            Game::Win {
+                is_first_player_win: true,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,260 @@
+use crate::search::evaluation::{Line, Score};
+use crate::search::random::SplitMix64;
+use crate::search::search_limits::SearchLimits;
+
+///Full strength, where the skill level changes nothing
+pub const MAX_SKILL_LEVEL: u8 = 20;
+///Rough strength of the lowest and highest skill levels
+const MIN_ELO: u16 = 800;
+const MAX_ELO: u16 = 2400;
+///Number of best moves to find, for the weaker levels to choose between
+pub(crate) const CANDIDATE_MOVES: usize = 4;
+const MIN_NODES: u64 = 500;
+///How many centipawns worse a move can be and still be chosen, for each level below the maximum
+const WEAKNESS_PER_LEVEL: i32 = 10;
+///Percent chance of playing any plausible move rather than a good one, for each level below the
+///maximum
+const MISTAKE_CHANCE_PER_LEVEL: u64 = 2;
+///From this level up, moves that let the opponent checkmate next move are never played if another
+///move doesn't, as long as the depth limit allows two plies
+const MIN_LEVEL_AVOIDING_MATE_IN_ONE: u8 = 5;
+///Stands in for a checkmate when comparing scores, larger than any score in centipawns
+const MATE_CENTIPAWNS: i32 = 100_000;
+
+///Makes the engine play weaker on purpose. Lower levels search less deeply, and choose between the
+///best few moves with more randomness, sometimes playing a plausible but worse move.
+///From level 5 up, the first two plies are always searched in full, even past the node cap, so a
+///move that allows mate in one is never chosen over one that doesn't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillLevel {
+    level: u8,
+    seed: Option<u64>,
+}
+
+impl SkillLevel {
+    ///Levels go from 0 to `MAX_SKILL_LEVEL`, higher levels are capped
+    pub fn new(level: u8) -> Self {
+        SkillLevel {
+            level: level.min(MAX_SKILL_LEVEL),
+            seed: None,
+        }
+    }
+
+    ///The level that plays closest to the given Elo rating
+    pub fn from_elo(elo: u16) -> Self {
+        let elo = elo.clamp(MIN_ELO, MAX_ELO);
+        let level = (elo - MIN_ELO) as u32 * MAX_SKILL_LEVEL as u32 / (MAX_ELO - MIN_ELO) as u32;
+        SkillLevel::new(level as u8)
+    }
+
+    ///Makes the choice of move repeatable, otherwise it changes every search
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    pub fn is_full_strength(&self) -> bool {
+        self.level >= MAX_SKILL_LEVEL
+    }
+
+    ///Lower levels search fewer plies and nodes
+    pub(crate) fn limit(&self, limits: &SearchLimits) -> SearchLimits {
+        if self.is_full_strength() {
+            return limits.clone();
+        }
+        let max_depth = 1 + self.level / 3;
+        let max_nodes = MIN_NODES << self.level;
+        SearchLimits {
+            max_depth: limits.max_depth.min(max_depth),
+            max_nodes: Some(
+                limits
+                    .max_nodes
+                    .map_or(max_nodes, |nodes| nodes.min(max_nodes)),
+            ),
+            ..limits.clone()
+        }
+    }
+
+    ///Iterations up to this depth always run to completion. Seeing whether a move allows mate in
+    ///one takes two plies, so the levels that avoid it always finish them, whatever the node cap.
+    pub(crate) fn min_depth(&self) -> u8 {
+        if self.level >= MIN_LEVEL_AVOIDING_MATE_IN_ONE {
+            2
+        } else {
+            1
+        }
+    }
+
+    ///Index of the line to play, given the best lines found, best first
+    pub(crate) fn choose_line(&self, lines: &[Line]) -> usize {
+        if self.is_full_strength() || lines.len() < 2 {
+            return 0;
+        }
+        let mut random = self
+            .seed
+            .map_or_else(SplitMix64::from_time, SplitMix64::new);
+        let levels_below_max = (MAX_SKILL_LEVEL - self.level) as i32;
+        let candidates: Vec<usize> = (0..lines.len())
+            .filter(|&index| self.is_plausible(&lines[index], &lines[0]))
+            .collect();
+
+        let mistake_chance = levels_below_max as u64 * MISTAKE_CHANCE_PER_LEVEL;
+        if random.next() % 100 < mistake_chance {
+            return candidates[random.below(candidates.len())];
+        }
+
+        let weakness = levels_below_max * WEAKNESS_PER_LEVEL;
+        candidates
+            .into_iter()
+            .max_by_key(|&index| {
+                to_centipawns(lines[index].score) + (random.next() % (weakness as u64 + 1)) as i32
+            })
+            .unwrap_or(0)
+    }
+
+    fn is_plausible(&self, line: &Line, best_line: &Line) -> bool {
+        self.level < MIN_LEVEL_AVOIDING_MATE_IN_ONE
+            || line.score != Score::Mate(-1)
+            || best_line.score == Score::Mate(-1)
+    }
+}
+
+fn to_centipawns(score: Score) -> i32 {
+    match score {
+        Score::Centipawns(centipawns) => centipawns,
+        Score::Mate(moves) if moves > 0 => MATE_CENTIPAWNS - moves,
+        Score::Mate(moves) => -MATE_CENTIPAWNS - moves,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(score: Score) -> Line {
+        Line {
+            score,
+            principal_variation: vec![],
+        }
+    }
+
+    #[test]
+    fn level_is_capped_at_max() {
+        let result = SkillLevel::new(50);
+
+        assert_eq!(MAX_SKILL_LEVEL, result.level());
+        assert!(result.is_full_strength());
+    }
+
+    #[test]
+    fn elo_maps_onto_levels() {
+        assert_eq!(0, SkillLevel::from_elo(400).level());
+        assert_eq!(10, SkillLevel::from_elo(1600).level());
+        assert_eq!(MAX_SKILL_LEVEL, SkillLevel::from_elo(3000).level());
+    }
+
+    #[test]
+    fn full_strength_does_not_limit_search() {
+        let limits = SearchLimits::new().with_max_depth(8);
+
+        let result = SkillLevel::new(MAX_SKILL_LEVEL).limit(&limits);
+
+        assert_eq!(limits, result);
+    }
+
+    #[test]
+    fn low_level_limits_depth_and_nodes() {
+        let limits = SearchLimits::new().with_max_depth(8);
+
+        let result = SkillLevel::new(0).limit(&limits);
+
+        assert_eq!(1, result.max_depth);
+        assert_eq!(Some(MIN_NODES), result.max_nodes);
+    }
+
+    #[test]
+    fn limits_are_never_raised() {
+        let limits = SearchLimits::new().with_max_depth(2).with_max_nodes(10);
+
+        let result = SkillLevel::new(15).limit(&limits);
+
+        assert_eq!(2, result.max_depth);
+        assert_eq!(Some(10), result.max_nodes);
+    }
+
+    #[test]
+    fn levels_avoiding_mate_in_one_finish_two_plies() {
+        assert_eq!(
+            1,
+            SkillLevel::new(MIN_LEVEL_AVOIDING_MATE_IN_ONE - 1).min_depth()
+        );
+        assert_eq!(
+            2,
+            SkillLevel::new(MIN_LEVEL_AVOIDING_MATE_IN_ONE).min_depth()
+        );
+    }
+
+    #[test]
+    fn full_strength_always_plays_best_line() {
+        let lines = [line(Score::Centipawns(10)), line(Score::Centipawns(5))];
+
+        let result = SkillLevel::new(MAX_SKILL_LEVEL)
+            .with_seed(1)
+            .choose_line(&lines);
+
+        assert_eq!(0, result);
+    }
+
+    #[test]
+    fn same_seed_chooses_same_line() {
+        let lines = [
+            line(Score::Centipawns(10)),
+            line(Score::Centipawns(5)),
+            line(Score::Centipawns(0)),
+        ];
+        let skill_level = SkillLevel::new(0).with_seed(7);
+
+        assert_eq!(
+            skill_level.choose_line(&lines),
+            skill_level.choose_line(&lines)
+        );
+    }
+
+    #[test]
+    fn low_level_sometimes_plays_worse_line() {
+        let lines = [line(Score::Centipawns(10)), line(Score::Centipawns(0))];
+
+        let result: Vec<usize> = (0..100)
+            .map(|seed| SkillLevel::new(0).with_seed(seed).choose_line(&lines))
+            .collect();
+
+        assert!(result.contains(&0));
+        assert!(result.contains(&1));
+    }
+
+    #[test]
+    fn mid_level_never_allows_mate_in_one() {
+        let lines = [line(Score::Centipawns(0)), line(Score::Mate(-1))];
+
+        let result: Vec<usize> = (0..100)
+            .map(|seed| SkillLevel::new(10).with_seed(seed).choose_line(&lines))
+            .collect();
+
+        assert!(result.iter().all(|&index| index == 0));
+    }
+
+    #[test]
+    fn lowest_level_can_allow_mate_in_one() {
+        let lines = [line(Score::Centipawns(0)), line(Score::Mate(-1))];
+
+        let result: Vec<usize> = (0..100)
+            .map(|seed| SkillLevel::new(0).with_seed(seed).choose_line(&lines))
+            .collect();
+
+        assert!(result.contains(&1));
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+///Lets another thread, e.g. a GUI or protocol front-end, stop a search that is running. Clones
+///share the same flag. A stopped search still returns the best move it has found.
+#[derive(Debug, Clone, Default)]
+pub struct StopHandle {
+    is_stopped: Arc<AtomicBool>,
+}
+
+impl StopHandle {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn stop(&self) {
+        self.is_stopped.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.is_stopped.load(Ordering::Relaxed)
+    }
+
+    ///Allow the handle to be used for another search
+    pub fn reset(&self) {
+        self.is_stopped.store(false, Ordering::Relaxed);
+    }
+}
+
+impl PartialEq for StopHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.is_stopped, &other.is_stopped)
+    }
+}
+
+impl Eq for StopHandle {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_handle_is_not_stopped() {
+        let handle = StopHandle::new();
+
+        assert!(!handle.is_stopped());
+    }
+
+    #[test]
+    fn clones_share_the_stop_flag() {
+        let handle = StopHandle::new();
+        let clone = handle.clone();
+
+        clone.stop();
+
+        assert!(handle.is_stopped());
+        assert_eq!(handle, clone);
+    }
+
+    #[test]
+    fn separate_handles_are_not_equal() {
+        assert_ne!(StopHandle::new(), StopHandle::new());
+    }
+
+    #[test]
+    fn reset_handle_is_not_stopped() {
+        let handle = StopHandle::new();
+        handle.stop();
+
+        handle.reset();
+
+        assert!(!handle.is_stopped());
+    }
+}
@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+///Moves we expect to still have to play when the time control doesn't say
+const DEFAULT_MOVES_TO_GO: u32 = 30;
+///Held back from every move to cover the time it takes to send the move
+const MOVE_OVERHEAD: Duration = Duration::from_millis(50);
+const HARD_LIMIT_MULTIPLIER: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Clock {
+    pub remaining: Duration,
+    pub increment: Duration,
+}
+
+impl Clock {
+    pub fn new(remaining: Duration) -> Self {
+        Clock {
+            remaining,
+            increment: Duration::ZERO,
+        }
+    }
+
+    pub fn with_increment(remaining: Duration, increment: Duration) -> Self {
+        Clock {
+            remaining,
+            increment,
+        }
+    }
+}
+
+///Both players' clocks. Without `moves_to_go` the remaining time has to last the rest of the
+///game (sudden death, or Fischer if there is an increment). With it, the clocks are topped up
+///after that many moves (classical).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeControl {
+    pub first_player: Clock,
+    pub second_player: Clock,
+    pub moves_to_go: Option<u32>,
+}
+
+///How long to spend on a move. Iterative deepening will not start another iteration after the
+///soft limit, and aborts mid-iteration at the hard limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeBudget {
+    pub soft: Duration,
+    pub hard: Duration,
+}
+
+impl TimeControl {
+    pub fn new(first_player: Clock, second_player: Clock) -> Self {
+        TimeControl {
+            first_player,
+            second_player,
+            moves_to_go: None,
+        }
+    }
+
+    pub fn sudden_death(remaining: Duration) -> Self {
+        TimeControl::new(Clock::new(remaining), Clock::new(remaining))
+    }
+
+    pub fn fischer(remaining: Duration, increment: Duration) -> Self {
+        TimeControl::new(
+            Clock::with_increment(remaining, increment),
+            Clock::with_increment(remaining, increment),
+        )
+    }
+
+    pub fn classical(remaining: Duration, moves_to_go: u32) -> Self {
+        TimeControl::sudden_death(remaining).with_moves_to_go(moves_to_go)
+    }
+
+    pub fn with_moves_to_go(mut self, moves_to_go: u32) -> Self {
+        self.moves_to_go = Some(moves_to_go);
+        self
+    }
+
+    pub fn clock(&self, is_first_player: bool) -> &Clock {
+        if is_first_player {
+            &self.first_player
+        } else {
+            &self.second_player
+        }
+    }
+
+    pub fn allocate(&self, is_first_player: bool) -> TimeBudget {
+        let clock = self.clock(is_first_player);
+        let available = clock.remaining.saturating_sub(MOVE_OVERHEAD);
+        let moves_to_go = self.moves_to_go.unwrap_or(DEFAULT_MOVES_TO_GO).max(1);
+
+        let soft = (available / moves_to_go + clock.increment).min(available);
+        let hard = (soft * HARD_LIMIT_MULTIPLIER).min(available / 2).max(soft);
+
+        TimeBudget { soft, hard }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sudden_death_spreads_time_over_expected_moves() {
+        let time_control = TimeControl::sudden_death(Duration::from_millis(30_050));
+
+        let result = time_control.allocate(true);
+
+        assert_eq!(Duration::from_secs(1), result.soft);
+        assert_eq!(Duration::from_secs(3), result.hard);
+    }
+
+    #[test]
+    fn fischer_adds_increment_to_budget() {
+        let time_control =
+            TimeControl::fischer(Duration::from_millis(30_050), Duration::from_secs(2));
+
+        let result = time_control.allocate(true);
+
+        assert_eq!(Duration::from_secs(3), result.soft);
+        assert_eq!(Duration::from_secs(9), result.hard);
+    }
+
+    #[test]
+    fn classical_spreads_time_over_moves_until_next_control() {
+        let time_control = TimeControl::classical(Duration::from_millis(10_050), 5);
+
+        let result = time_control.allocate(true);
+
+        assert_eq!(Duration::from_secs(2), result.soft);
+        assert_eq!(Duration::from_secs(5), result.hard);
+    }
+
+    #[test]
+    fn given_last_move_before_control_may_use_all_available_time() {
+        let time_control = TimeControl::classical(Duration::from_millis(10_050), 1);
+
+        let result = time_control.allocate(true);
+
+        assert_eq!(Duration::from_secs(10), result.soft);
+        assert_eq!(Duration::from_secs(10), result.hard);
+    }
+
+    #[test]
+    fn budget_never_exceeds_remaining_time() {
+        let time_control = TimeControl::fischer(Duration::from_millis(100), Duration::from_secs(5));
+
+        let result = time_control.allocate(true);
+
+        assert!(result.soft <= Duration::from_millis(50));
+        assert!(result.hard <= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn allocates_from_the_clock_of_the_player_to_move() {
+        let time_control = TimeControl::new(
+            Clock::new(Duration::from_millis(30_050)),
+            Clock::new(Duration::from_millis(60_050)),
+        );
+
+        let first_player = time_control.allocate(true);
+        let second_player = time_control.allocate(false);
+
+        assert_eq!(Duration::from_secs(1), first_player.soft);
+        assert_eq!(Duration::from_secs(2), second_player.soft);
+    }
+}
@@ -0,0 +1,290 @@
+use crate::moves::Move;
+use crate::search::evaluation::MATE_THRESHOLD;
+use std::mem::size_of;
+use std::sync::Mutex;
+
+pub const DEFAULT_SIZE_IN_MEGABYTES: usize = 16;
+
+///Whether a stored score is exact, or only bounds the real score because of an alpha-beta cutoff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub hash: u64,
+    pub depth: u8,
+    pub bound: Bound,
+    score: i32,
+    pub best_move: Option<Move>,
+    generation: u8,
+}
+
+impl Entry {
+    ///Mate scores are stored relative to the position they were found in, this converts them back
+    ///to be relative to the root of the search probing them, `ply` plies away
+    pub fn score(&self, ply: u8) -> i32 {
+        score_from_table(self.score, ply)
+    }
+}
+
+type Slot = Mutex<Option<Entry>>;
+
+///Fixed-size cache of search results, keyed by `GameState::zobrist_hash`. When two positions
+///share a slot the deeper or more recent result is kept. Each slot is locked separately, so
+///search threads can share one table.
+pub struct TranspositionTable {
+    entries: Vec<Slot>,
+    generation: u8,
+}
+
+impl TranspositionTable {
+    pub fn new(size_in_megabytes: usize) -> Self {
+        let capacity = (size_in_megabytes * 1024 * 1024 / size_of::<Slot>()).max(1);
+        TranspositionTable {
+            entries: (0..capacity).map(|_| Mutex::new(None)).collect(),
+            generation: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|slot| slot.lock().unwrap().is_some())
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries
+            .iter()
+            .all(|slot| slot.lock().unwrap().is_none())
+    }
+
+    ///Forget everything, e.g. before starting a new game
+    pub fn clear(&mut self) {
+        self.entries
+            .iter_mut()
+            .for_each(|slot| *slot.get_mut().unwrap() = None);
+        self.generation = 0;
+    }
+
+    ///Mark every current entry as stale. Stale entries are still used, but are the first to be
+    ///replaced.
+    pub fn age(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    pub fn probe(&self, hash: u64) -> Option<Entry> {
+        self.entries[self.index(hash)]
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|entry| entry.hash == hash)
+            .cloned()
+    }
+
+    pub fn store(
+        &self,
+        hash: u64,
+        depth: u8,
+        bound: Bound,
+        score: i32,
+        best_move: Option<Move>,
+        ply: u8,
+    ) {
+        let generation = self.generation;
+        let mut slot = self.entries[self.index(hash)].lock().unwrap();
+        let should_replace = match &*slot {
+            None => true,
+            Some(existing) => {
+                existing.hash == hash
+                    || existing.generation != generation
+                    || depth >= existing.depth
+            }
+        };
+
+        if should_replace {
+            let best_move = best_move.or_else(|| {
+                slot.as_ref()
+                    .filter(|existing| existing.hash == hash)
+                    .and_then(|existing| existing.best_move.clone())
+            });
+            *slot = Some(Entry {
+                hash,
+                depth,
+                bound,
+                score: score_to_table(score, ply),
+                best_move,
+                generation,
+            });
+        }
+    }
+
+    fn index(&self, hash: u64) -> usize {
+        (hash % self.entries.len() as u64) as usize
+    }
+}
+
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        TranspositionTable::new(DEFAULT_SIZE_IN_MEGABYTES)
+    }
+}
+
+fn score_to_table(score: i32, ply: u8) -> i32 {
+    if score > MATE_THRESHOLD {
+        score + ply as i32
+    } else if score < -MATE_THRESHOLD {
+        score - ply as i32
+    } else {
+        score
+    }
+}
+
+fn score_from_table(score: i32, ply: u8) -> i32 {
+    if score > MATE_THRESHOLD {
+        score - ply as i32
+    } else if score < -MATE_THRESHOLD {
+        score + ply as i32
+    } else {
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::evaluation::MATE;
+    use crate::state::coordinates::Coordinate;
+
+    const SMALL_TABLE_MEGABYTES: usize = 1;
+
+    #[test]
+    fn table_size_is_bounded_by_megabytes() {
+        let table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+
+        assert!(table.capacity() * size_of::<Slot>() <= 1024 * 1024);
+        assert!(table.capacity() > 1000);
+    }
+
+    #[test]
+    fn stored_entry_can_be_probed() {
+        let table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+        let best_move = Move::PawnMove(Coordinate::E2, Coordinate::E4);
+
+        table.store(42, 3, Bound::Exact, 17, Some(best_move.clone()), 1);
+        let result = table.probe(42).unwrap();
+
+        assert_eq!(3, result.depth);
+        assert_eq!(Bound::Exact, result.bound);
+        assert_eq!(17, result.score(1));
+        assert_eq!(Some(best_move), result.best_move);
+    }
+
+    #[test]
+    fn given_different_hash_in_same_slot_probe_misses() {
+        let table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+        let capacity = table.capacity() as u64;
+
+        table.store(1, 3, Bound::Exact, 17, None, 1);
+
+        assert!(table.probe(1 + capacity).is_none());
+    }
+
+    #[test]
+    fn shallower_entry_does_not_replace_deeper_entry_from_same_search() {
+        let table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+        let capacity = table.capacity() as u64;
+
+        table.store(1, 5, Bound::Exact, 17, None, 1);
+        table.store(1 + capacity, 2, Bound::Exact, 3, None, 1);
+
+        assert!(table.probe(1).is_some());
+        assert!(table.probe(1 + capacity).is_none());
+    }
+
+    #[test]
+    fn aged_entry_is_replaced_by_shallower_entry() {
+        let mut table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+        let capacity = table.capacity() as u64;
+
+        table.store(1, 5, Bound::Exact, 17, None, 1);
+        table.age();
+        table.store(1 + capacity, 2, Bound::Exact, 3, None, 1);
+
+        assert!(table.probe(1).is_none());
+        assert!(table.probe(1 + capacity).is_some());
+    }
+
+    #[test]
+    fn clear_removes_all_entries() {
+        let mut table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+        table.store(1, 5, Bound::Exact, 17, None, 1);
+
+        table.clear();
+
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn storing_without_best_move_keeps_previous_best_move() {
+        let table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+        let best_move = Move::PawnMove(Coordinate::E2, Coordinate::E4);
+
+        table.store(1, 2, Bound::Exact, 17, Some(best_move.clone()), 1);
+        table.store(1, 3, Bound::Upper, 10, None, 1);
+
+        assert_eq!(Some(best_move), table.probe(1).unwrap().best_move);
+    }
+
+    #[test]
+    fn table_can_be_shared_between_threads() {
+        let table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+
+        std::thread::scope(|scope| {
+            for hash in 1..=4 {
+                let table = &table;
+                scope.spawn(move || table.store(hash, 3, Bound::Exact, 17, None, 1));
+            }
+        });
+
+        assert_eq!(4, table.len());
+    }
+
+    #[test]
+    fn mate_score_is_relative_to_ply_it_is_probed_from() {
+        let table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+        let mate_two_plies_after_ply_5 = MATE - 7;
+
+        table.store(1, 3, Bound::Exact, mate_two_plies_after_ply_5, None, 5);
+        let result = table.probe(1).unwrap().score(9);
+
+        assert_eq!(MATE - 11, result);
+    }
+
+    #[test]
+    fn mated_score_is_relative_to_ply_it_is_probed_from() {
+        let table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+
+        table.store(1, 3, Bound::Exact, -MATE + 7, None, 5);
+        let result = table.probe(1).unwrap().score(9);
+
+        assert_eq!(-MATE + 11, result);
+    }
+
+    #[test]
+    fn regular_score_is_not_adjusted() {
+        let table = TranspositionTable::new(SMALL_TABLE_MEGABYTES);
+
+        table.store(1, 3, Bound::Exact, 250, None, 5);
+
+        assert_eq!(250, table.probe(1).unwrap().score(9));
+    }
+}
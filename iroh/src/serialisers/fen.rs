@@ -1,5 +1,6 @@
 use crate::state::coordinates::Coordinate;
 use crate::state::tile::Tile;
+use crate::state::variant::Variant;
 use crate::state::GameState;
 
 fn coordinate_from_rank_and_file(rank: u8, file: u8) -> Coordinate {
@@ -9,39 +10,46 @@ fn coordinate_from_rank_and_file(rank: u8, file: u8) -> Coordinate {
 pub fn parse_fen(fen: &str, game_state: &mut GameState) {
     let mut rank = 7_u8;
     let mut file = 0_u8;
+    //Square of the piece just placed, which a promoted marker can follow
+    let mut last_piece = None;
     let mut blocks = fen.split_whitespace();
+    let mut board_and_pocket = blocks.next().expect("Invalid FEN syntax").split('[');
 
-    for char in blocks.next().expect("Invalid FEN syntax").chars() {
+    for char in board_and_pocket.next().expect("Invalid FEN syntax").chars() {
         if char.eq(&'/') {
             rank -= 1;
             file = 0;
+            last_piece = None;
             continue;
         }
         if char.is_ascii_digit() {
             file += char as u8 - 0x30;
+            last_piece = None;
+            continue;
+        }
+        if char.eq(&'~') {
+            //Crazyhouse marks promoted pieces with a tilde after the piece
+            let promoted = last_piece.take().expect("Invalid FEN syntax");
+            game_state.promoted_pieces.push(promoted);
             continue;
         }
 
-        let tile = match char {
-            'R' => Tile::FIRST_ROOK,
-            'r' => Tile::SECOND_ROOK,
-            'N' => Tile::FIRST_KNIGHT,
-            'n' => Tile::SECOND_KNIGHT,
-            'B' => Tile::FIRST_BISHOP,
-            'b' => Tile::SECOND_BISHOP,
-            'Q' => Tile::FIRST_QUEEN,
-            'q' => Tile::SECOND_QUEEN,
-            'K' => Tile::FIRST_KING,
-            'k' => Tile::SECOND_KING,
-            'P' => Tile::FIRST_PAWN,
-            'p' => Tile::SECOND_PAWN,
-            _ => Tile::EMPTY,
-        };
-        game_state.board[coordinate_from_rank_and_file(rank, file)] = tile;
+        let tile = parse_fen_piece(char);
+        let coordinate = coordinate_from_rank_and_file(rank, file);
+        game_state.board[coordinate] = tile;
+        last_piece = tile.is_occupied().then_some(coordinate);
 
         file += 1;
     }
 
+    if let Some(pocket) = board_and_pocket.next() {
+        for char in pocket.trim_end_matches(']').chars() {
+            game_state
+                .captured_pieces
+                .add_to_pocket(parse_fen_piece(char));
+        }
+    }
+
     let player_to_move = blocks.next().expect("Invalid FEN syntax");
     match player_to_move {
         "w" => {
@@ -71,6 +79,51 @@ pub fn parse_fen(fen: &str, game_state: &mut GameState) {
             _ => panic!("Invalid FEN syntax"),
         };
     }
+
+    if let Some(en_passant) = blocks.next().filter(|block| *block != "-") {
+        game_state.en_passant_target = Some(parse_fen_coordinate(en_passant));
+    }
+
+    if let Some(halfmove_clock) = blocks.next() {
+        game_state.halfmove_clock = halfmove_clock.parse().expect("Invalid FEN syntax");
+    }
+
+    //Three-check appends the checks given by each player, e.g. `+1+0`
+    if let Some(checks_given) = blocks.find(|block| block.starts_with('+')) {
+        let mut checks_given = checks_given
+            .split('+')
+            .skip(1)
+            .map(|checks| checks.parse::<u8>().expect("Invalid FEN syntax"));
+        game_state.first_player_checks_given = checks_given.next().expect("Invalid FEN syntax");
+        game_state.second_player_checks_given = checks_given.next().expect("Invalid FEN syntax");
+    }
+}
+
+fn parse_fen_coordinate(name: &str) -> Coordinate {
+    match name.as_bytes() {
+        [file @ b'a'..=b'h', rank @ b'1'..=b'8'] => {
+            coordinate_from_rank_and_file(rank - b'1', file - b'a')
+        }
+        _ => panic!("Invalid FEN syntax"),
+    }
+}
+
+fn parse_fen_piece(char: char) -> Tile {
+    match char {
+        'R' => Tile::FIRST_ROOK,
+        'r' => Tile::SECOND_ROOK,
+        'N' => Tile::FIRST_KNIGHT,
+        'n' => Tile::SECOND_KNIGHT,
+        'B' => Tile::FIRST_BISHOP,
+        'b' => Tile::SECOND_BISHOP,
+        'Q' => Tile::FIRST_QUEEN,
+        'q' => Tile::SECOND_QUEEN,
+        'K' => Tile::FIRST_KING,
+        'k' => Tile::SECOND_KING,
+        'P' => Tile::FIRST_PAWN,
+        'p' => Tile::SECOND_PAWN,
+        _ => Tile::EMPTY,
+    }
 }
 
 pub fn generate_fen(game_state: &GameState) -> String {
@@ -88,6 +141,11 @@ pub fn generate_fen(game_state: &GameState) -> String {
                 };
                 let glyph = generate_fen_piece(tile);
                 result.push(glyph);
+                if game_state.variant == Variant::Crazyhouse
+                    && game_state.promoted_pieces.contains(&coordinate)
+                {
+                    result.push('~');
+                }
             } else {
                 blank_tiles_count += 1;
             }
@@ -104,7 +162,11 @@ pub fn generate_fen(game_state: &GameState) -> String {
         }
     }
 
-    result.push_str(&*format!(
+    if game_state.variant == Variant::Crazyhouse {
+        result.push_str(&generate_pocket_metadata(game_state));
+    }
+
+    result.push_str(&format!(
         " {} {} - 0 1",
         if game_state.is_first_player_turn {
             "w"
@@ -113,6 +175,13 @@ pub fn generate_fen(game_state: &GameState) -> String {
         },
         generate_castling_metadata(game_state)
     ));
+
+    if game_state.variant == Variant::ThreeCheck {
+        result.push_str(&format!(
+            " +{}+{}",
+            game_state.first_player_checks_given, game_state.second_player_checks_given
+        ));
+    }
     result
 }
 
@@ -139,6 +208,22 @@ fn generate_castling_metadata(game_state: &GameState) -> String {
     result
 }
 
+fn generate_pocket_metadata(game_state: &GameState) -> String {
+    let mut result = String::from("[");
+
+    for tile in game_state
+        .captured_pieces
+        .pocket(true)
+        .into_iter()
+        .chain(game_state.captured_pieces.pocket(false))
+    {
+        result.push(generate_fen_piece(tile));
+    }
+
+    result.push(']');
+    result
+}
+
 fn generate_fen_piece(tile: Tile) -> char {
     let piece_type = match tile {
         Tile::FIRST_ROOK | Tile::SECOND_ROOK => 'r',
@@ -181,10 +266,7 @@ mod tests {
 
         parse_fen(fen_with_uppercase_king, &mut game_state);
 
-        assert_eq!(
-            true,
-            game_state.board[Coordinate::E8].is_owned_by_first_player()
-        );
+        assert!(game_state.board[Coordinate::E8].is_owned_by_first_player());
     }
 
     #[test]
@@ -194,10 +276,7 @@ mod tests {
 
         parse_fen(fen_with_lowercase_king, &mut game_state);
 
-        assert_eq!(
-            false,
-            game_state.board[Coordinate::E8].is_owned_by_first_player()
-        );
+        assert!(!game_state.board[Coordinate::E8].is_owned_by_first_player());
     }
 
     #[test]
@@ -265,13 +344,90 @@ mod tests {
     fn given_metadata_says_so_first_player_starts() {
         let state = GameState::from_fen("8/8/8/8/8/8/8/8 w - - 0 1");
 
-        assert_eq!(true, state.is_first_player_turn);
+        assert!(state.is_first_player_turn);
     }
 
     #[test]
     fn given_metadata_says_so_second_player_starts() {
         let state = GameState::from_fen("8/8/8/8/8/8/8/8 b - - 0 1");
 
-        assert_eq!(false, state.is_first_player_turn);
+        assert!(!state.is_first_player_turn);
+    }
+
+    #[test]
+    fn parse_crazyhouse_pocket() {
+        let state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[QNp] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+
+        assert_eq!(
+            vec![Tile::FIRST_QUEEN, Tile::FIRST_KNIGHT],
+            state.captured_pieces.pocket(true)
+        );
+        assert_eq!(vec![Tile::SECOND_PAWN], state.captured_pieces.pocket(false));
+    }
+
+    #[test]
+    fn parse_crazyhouse_promoted_piece() {
+        let state = GameState::from_fen_with_variant(
+            "2Q~1k3/8/8/8/8/8/8/4K3[] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+
+        assert_eq!(Tile::FIRST_QUEEN, state.board[Coordinate::C8]);
+        assert_eq!(vec![Coordinate::C8], state.promoted_pieces);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid FEN syntax")]
+    fn promoted_marker_must_follow_a_piece() {
+        GameState::from_fen_with_variant("~1k5/8/8/8/8/8/8/4K3[] w - - 0 1", Variant::Crazyhouse);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid FEN syntax")]
+    fn promoted_marker_must_not_follow_empty_squares() {
+        GameState::from_fen_with_variant("2~1k4/8/8/8/8/8/8/4K3[] w - - 0 1", Variant::Crazyhouse);
+    }
+
+    #[test]
+    fn generate_crazyhouse_fen_round_trips() {
+        let test_fen = "r1b1k2r/ppp2ppp/2n5/3Q~4/8/8/PPP2PPP/RNB1K1NR[BPPnq] b KQkq - 0 1";
+        let state = GameState::from_fen_with_variant(test_fen, Variant::Crazyhouse);
+
+        let result = generate_fen(&state);
+
+        assert_eq!(test_fen, result);
+    }
+
+    #[test]
+    fn standard_fen_does_not_include_pocket() {
+        let state = GameState::from_fen("4k3/8/8/8/8/8/8/4K3[Q] w - - 0 1");
+
+        let result = generate_fen(&state);
+
+        assert_eq!("4k3/8/8/8/8/8/8/4K3 w - - 0 1", result);
+    }
+
+    #[test]
+    fn parse_three_check_checks_given() {
+        let state = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3 w - - 0 1 +2+1",
+            Variant::ThreeCheck,
+        );
+
+        assert_eq!(2, state.first_player_checks_given);
+        assert_eq!(1, state.second_player_checks_given);
+    }
+
+    #[test]
+    fn generate_three_check_fen_round_trips() {
+        let test_fen = "4k3/8/8/8/8/8/8/4K3 b - - 0 1 +0+2";
+        let state = GameState::from_fen_with_variant(test_fen, Variant::ThreeCheck);
+
+        let result = generate_fen(&state);
+
+        assert_eq!(test_fen, result);
     }
 }
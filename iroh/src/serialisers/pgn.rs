@@ -2,10 +2,8 @@ use crate::game::Game;
 
 pub fn generate_pgn(sans: &[String], game: &Game) -> String {
     let mut result = String::new();
-    let mut i = 1;
-    for pair in sans.chunks(2) {
-        result = generate_pgn_chunk(result, i, pair);
-        i += 1;
+    for (i, pair) in sans.chunks(2).enumerate() {
+        result = generate_pgn_chunk(result, i as u8 + 1, pair);
     }
 
     match game {
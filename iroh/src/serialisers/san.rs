@@ -21,6 +21,14 @@ pub fn generate_pawn_promotion_san(to: Coordinate, promote_to: Tile) -> String {
     format!("{}={}", to, to_piece_identifier(promote_to))
 }
 
+pub fn generate_pawn_attack_promotion_san(
+    from: Coordinate,
+    to: Coordinate,
+    promote_to: Tile,
+) -> String {
+    format!("{}x{}={}", from.file(), to, to_piece_identifier(promote_to))
+}
+
 pub fn generate_castling_san(is_kingside: bool) -> String {
     if is_kingside {
         String::from("O-O")
@@ -29,6 +37,13 @@ pub fn generate_castling_san(is_kingside: bool) -> String {
     }
 }
 
+pub fn generate_drop_san(tile: Tile, to: Coordinate) -> String {
+    match tile {
+        Tile::FIRST_PAWN | Tile::SECOND_PAWN => format!("P@{}", to),
+        _ => format!("{}@{}", to_piece_identifier(tile), to),
+    }
+}
+
 fn to_piece_identifier(tile: Tile) -> char {
     match tile {
         Tile::FIRST_PAWN | Tile::SECOND_PAWN => {
@@ -83,4 +98,11 @@ mod tests {
 
         assert_eq!(result, "O-O");
     }
+
+    #[test]
+    fn given_pawn_drop_should_generate_san() {
+        let result = generate_drop_san(Tile::FIRST_PAWN, Coordinate::E4);
+
+        assert_eq!("P@e4", result);
+    }
 }
@@ -25,6 +25,41 @@ impl CapturedPieces {
         self.second_player.push(tile);
         self.last_capture_turn = turn_number;
     }
+
+    /// The pieces a player has captured, owned by that player so they can be dropped
+    /// back onto the board in Crazyhouse.
+    pub fn pocket(&self, is_first_player: bool) -> Vec<Tile> {
+        let captured = if is_first_player {
+            &self.second_player
+        } else {
+            &self.first_player
+        };
+        captured
+            .iter()
+            .map(|tile| tile.inverted_ownership())
+            .collect()
+    }
+
+    pub(crate) fn add_to_pocket(&mut self, tile: Tile) {
+        if tile.is_owned_by_first_player() {
+            self.second_player.push(tile.inverted_ownership());
+        } else {
+            self.first_player.push(tile.inverted_ownership());
+        }
+    }
+
+    pub(crate) fn remove_from_pocket(&mut self, tile: Tile) {
+        let captured = if tile.is_owned_by_first_player() {
+            &mut self.second_player
+        } else {
+            &mut self.first_player
+        };
+        let index = captured
+            .iter()
+            .position(|captured_tile| *captured_tile == tile.inverted_ownership())
+            .expect("Cannot drop a piece that is not in the pocket");
+        captured.remove(index);
+    }
 }
 
 impl Default for CapturedPieces {
@@ -32,3 +67,39 @@ impl Default for CapturedPieces {
         CapturedPieces::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pocket_contains_captured_pieces_owned_by_capturer() {
+        let mut captured_pieces = CapturedPieces::new();
+
+        captured_pieces.captured_second_player(Tile::SECOND_KNIGHT, 3);
+        captured_pieces.captured_first_player(Tile::FIRST_PAWN, 4);
+
+        assert_eq!(vec![Tile::FIRST_KNIGHT], captured_pieces.pocket(true));
+        assert_eq!(vec![Tile::SECOND_PAWN], captured_pieces.pocket(false));
+    }
+
+    #[test]
+    fn removing_from_pocket_only_removes_one_piece() {
+        let mut captured_pieces = CapturedPieces::new();
+        captured_pieces.add_to_pocket(Tile::FIRST_PAWN);
+        captured_pieces.add_to_pocket(Tile::FIRST_PAWN);
+
+        captured_pieces.remove_from_pocket(Tile::FIRST_PAWN);
+
+        assert_eq!(vec![Tile::FIRST_PAWN], captured_pieces.pocket(true));
+    }
+
+    #[test]
+    fn adding_to_pocket_does_not_count_as_capture() {
+        let mut captured_pieces = CapturedPieces::new();
+
+        captured_pieces.add_to_pocket(Tile::SECOND_QUEEN);
+
+        assert_eq!(0, captured_pieces.last_capture_turn);
+    }
+}
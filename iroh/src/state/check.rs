@@ -6,22 +6,45 @@ use crate::moves::coordinate_transformers::{
 use crate::state::board::Board;
 use crate::state::coordinates::Coordinate;
 use crate::state::tile::Tile;
+use crate::state::variant::Variant;
 use crate::state::GameState;
 
 pub fn is_check(is_first_player: bool, game_state: &GameState) -> bool {
-    let king = game_state
-        .board
-        .get_all_pieces_belonging_to_player(is_first_player)
-        .into_iter()
-        .find(|tile| tile.0 == Tile::FIRST_KING || tile.0 == Tile::SECOND_KING);
+    //Antichess kings are not royal, they can be captured like any other piece
+    if game_state.variant == Variant::Antichess {
+        return false;
+    }
+
+    let king = find_king(is_first_player, game_state);
 
     if let Some(king) = king {
-        would_be_check(king, game_state)
+        //Atomic kings can touch, neither can attack the other without exploding itself
+        if game_state.variant == Variant::Atomic && are_kings_touching(king, game_state) {
+            false
+        } else {
+            would_be_check(king, game_state)
+        }
     } else {
         false
     }
 }
 
+pub fn find_king(is_first_player: bool, game_state: &GameState) -> Option<(Tile, Coordinate)> {
+    game_state
+        .board
+        .get_all_pieces_belonging_to_player(is_first_player)
+        .into_iter()
+        .find(|tile| tile.0 == Tile::FIRST_KING || tile.0 == Tile::SECOND_KING)
+}
+
+fn are_kings_touching(king: (Tile, Coordinate), game_state: &GameState) -> bool {
+    let enemy_king = king.0.inverted_ownership();
+    KING_STATIC_TRANSFORMERS
+        .iter()
+        .filter_map(|transform| transform(king.1))
+        .any(|coordinate| game_state.board[coordinate] == enemy_king)
+}
+
 pub fn would_be_check(king: (Tile, Coordinate), game_state: &GameState) -> bool {
     let mut result = false;
     let is_first_player = king.0.is_owned_by_first_player();
@@ -216,12 +239,42 @@ mod tests {
         queen_check_bottomright {"8/8/8/8/8/4K3/8/6q1 w - - 0 1";true}
     }
 
+    #[test]
+    fn given_atomic_touching_kings_are_not_in_check() {
+        let game_state =
+            GameState::from_fen_with_variant("4r3/8/8/8/8/3k4/4K3/8 w - - 0 1", Variant::Atomic);
+
+        let result = is_check(true, &game_state);
+
+        assert!(!result);
+    }
+
+    #[test]
+    fn given_atomic_kings_not_touching_check_still_applies() {
+        let game_state =
+            GameState::from_fen_with_variant("8/8/8/3k4/8/8/4K3/4r3 w - - 0 1", Variant::Atomic);
+
+        let result = is_check(true, &game_state);
+
+        assert!(result);
+    }
+
+    #[test]
+    fn given_antichess_king_is_never_in_check() {
+        let game_state =
+            GameState::from_fen_with_variant("4k3/8/8/8/8/8/8/4K2r w - - 0 1", Variant::Antichess);
+
+        let result = is_check(true, &game_state);
+
+        assert!(!result);
+    }
+
     #[test]
     fn pawn_checks_in_opposite_direction_during_second_players_turn() {
         let game_state = GameState::from_fen("8/8/8/8/8/4k3/3P4/8 b - - 0 1");
 
         let result = is_check(false, &game_state);
 
-        assert_eq!(true, result);
+        assert!(result);
     }
 }
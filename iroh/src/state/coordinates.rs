@@ -149,16 +149,10 @@ impl Coordinate {
     }
 
     fn checked_sub(&self, input: u8) -> Option<Coordinate> {
-        let result = self.0.checked_sub(input).map(Coordinate);
-        if let Some(coordinate) = result {
-            if coordinate.is_on_board() {
-                Some(coordinate)
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+        self.0
+            .checked_sub(input)
+            .map(Coordinate)
+            .filter(|coordinate| coordinate.is_on_board())
     }
 
     pub fn is_last_rank(&self) -> bool {
@@ -184,6 +178,20 @@ impl Coordinate {
     pub fn is_first_rank(&self) -> bool {
         self.0 < 8
     }
+
+    ///One of d4, d5, e4 or e5
+    pub fn is_centre(&self) -> bool {
+        self.distance_from_centre() == 0
+    }
+
+    ///Number of king moves needed to reach the nearest centre tile
+    pub fn distance_from_centre(&self) -> u8 {
+        let file = self.0 & 7;
+        let rank = self.0 >> 4;
+        let file_distance = if file < 4 { 3 - file } else { file - 4 };
+        let rank_distance = if rank < 4 { 3 - rank } else { rank - 4 };
+        file_distance.max(rank_distance)
+    }
 }
 
 impl Display for Coordinate {
@@ -216,7 +224,7 @@ mod tests {
 
         let result = coordinate.as_usize();
 
-        assert_eq!(18 as usize, result);
+        assert_eq!(18_usize, result);
     }
 
     #[test]
@@ -486,7 +494,7 @@ mod tests {
 
         let result = coordinate.is_last_rank();
 
-        assert_eq!(false, result);
+        assert!(!result);
     }
 
     #[test]
@@ -495,7 +503,7 @@ mod tests {
 
         let result = coordinate.is_last_rank();
 
-        assert_eq!(true, result);
+        assert!(result);
     }
 
     #[test]
@@ -504,7 +512,7 @@ mod tests {
 
         let result = coordinate.is_first_rank();
 
-        assert_eq!(false, result);
+        assert!(!result);
     }
 
     #[test]
@@ -513,7 +521,7 @@ mod tests {
 
         let result = coordinate.is_first_rank();
 
-        assert_eq!(true, result);
+        assert!(result);
     }
 
     #[test]
@@ -522,7 +530,7 @@ mod tests {
 
         let result = coordinate.is_rank_7();
 
-        assert_eq!(false, result);
+        assert!(!result);
     }
 
     #[test]
@@ -531,7 +539,7 @@ mod tests {
 
         let result = coordinate.is_rank_7();
 
-        assert_eq!(true, result);
+        assert!(result);
     }
 
     #[test]
@@ -540,7 +548,7 @@ mod tests {
 
         let result = coordinate.is_rank_2();
 
-        assert_eq!(false, result);
+        assert!(!result);
     }
 
     #[test]
@@ -549,6 +557,24 @@ mod tests {
 
         let result = coordinate.is_rank_2();
 
-        assert_eq!(true, result);
+        assert!(result);
+    }
+
+    #[test]
+    fn centre_tiles_are_the_middle_four() {
+        assert!(Coordinate::D4.is_centre());
+        assert!(Coordinate::E4.is_centre());
+        assert!(Coordinate::D5.is_centre());
+        assert!(Coordinate::E5.is_centre());
+        assert!(!Coordinate::C4.is_centre());
+        assert!(!Coordinate::E6.is_centre());
+    }
+
+    #[test]
+    fn distance_from_centre_counts_king_moves() {
+        assert_eq!(3, Coordinate::A1.distance_from_centre());
+        assert_eq!(3, Coordinate::H8.distance_from_centre());
+        assert_eq!(1, Coordinate::F6.distance_from_centre());
+        assert_eq!(2, Coordinate::E2.distance_from_centre());
     }
 }
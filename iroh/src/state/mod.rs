@@ -1,11 +1,14 @@
-use crate::game::Game;
 use crate::moves::move_generation::generate_moves;
 use crate::moves::resolve_move::resolve_move;
 use crate::moves::Move;
 use crate::serialisers::fen::{generate_fen, parse_fen};
 use crate::state::board::Board;
 use crate::state::captured_pieces::CapturedPieces;
-use crate::state::check::is_check;
+use crate::state::check::{find_king, is_check};
+use crate::state::coordinates::Coordinate;
+use crate::state::tile::Tile;
+use crate::state::variant::Variant;
+use crate::state::zobrist::zobrist_hash;
 use std::collections::HashMap;
 
 pub mod board;
@@ -13,8 +16,13 @@ pub mod captured_pieces;
 pub(crate) mod check;
 pub mod coordinates;
 pub mod tile;
+pub mod variant;
+mod zobrist;
 
 const STARTING_POSITION_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+///How PGN writes a null move
+const NULL_MOVE_SAN: &str = "--";
+const FIFTY_MOVE_RULE_PLIES: u16 = 100;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GameState {
@@ -28,6 +36,16 @@ pub struct GameState {
     pub first_player_can_castle_queenside: bool,
     pub second_player_can_castle_kingside: bool,
     pub second_player_can_castle_queenside: bool,
+    pub variant: Variant,
+    pub first_player_checks_given: u8,
+    pub second_player_checks_given: u8,
+    pub(crate) promoted_pieces: Vec<Coordinate>,
+    ///Square a pawn that has just moved two squares passed over, where it can be taken en passant
+    pub(crate) en_passant_target: Option<Coordinate>,
+    ///Hashes of the earlier positions since the last irreversible move, oldest first
+    pub(crate) previous_positions: Vec<u64>,
+    ///Plies since the last pawn move or capture, for the fifty-move rule
+    pub(crate) halfmove_clock: u16,
 }
 
 impl GameState {
@@ -35,7 +53,15 @@ impl GameState {
         GameState::from_fen(STARTING_POSITION_FEN)
     }
 
+    pub fn with_variant(variant: Variant) -> GameState {
+        GameState::from_fen_with_variant(variant.starting_fen(), variant)
+    }
+
     pub fn from_fen(fen: &str) -> GameState {
+        GameState::from_fen_with_variant(fen, Variant::Standard)
+    }
+
+    pub fn from_fen_with_variant(fen: &str, variant: Variant) -> GameState {
         let mut state = GameState {
             turn_number: 1,
             is_first_player_turn: true,
@@ -47,6 +73,13 @@ impl GameState {
             second_player_can_castle_queenside: false,
             possible_moves: vec![],
             sans: vec![],
+            variant,
+            first_player_checks_given: 0,
+            second_player_checks_given: 0,
+            promoted_pieces: vec![],
+            en_passant_target: None,
+            previous_positions: vec![],
+            halfmove_clock: 0,
         };
         parse_fen(fen, &mut state);
         let is_first_player_turn = state.is_first_player_turn;
@@ -64,10 +97,46 @@ impl GameState {
         is_check(is_first_player, self)
     }
 
+    pub(crate) fn has_king(&self, is_first_player: bool) -> bool {
+        find_king(is_first_player, self).is_some()
+    }
+
+    pub(crate) fn is_king_on_hill(&self, is_first_player: bool) -> bool {
+        find_king(is_first_player, self).is_some_and(|king| king.1.is_centre())
+    }
+
+    pub(crate) fn is_king_on_last_rank(&self, is_first_player: bool) -> bool {
+        find_king(is_first_player, self).is_some_and(|king| king.1.is_last_rank())
+    }
+
+    pub(crate) fn has_pieces(&self, is_first_player: bool) -> bool {
+        !self
+            .board
+            .get_all_pieces_belonging_to_player(is_first_player)
+            .is_empty()
+    }
+
+    ///Pieces other than pawns and the king, without which a player is likely to be in zugzwang
+    pub(crate) fn has_non_pawn_material(&self, is_first_player: bool) -> bool {
+        self.board
+            .get_all_pieces_belonging_to_player(is_first_player)
+            .into_iter()
+            .any(|(tile, _)| {
+                !matches!(
+                    tile,
+                    Tile::FIRST_PAWN | Tile::SECOND_PAWN | Tile::FIRST_KING | Tile::SECOND_KING
+                )
+            })
+    }
+
     pub fn generate_fen(&self) -> String {
         generate_fen(self)
     }
 
+    pub fn zobrist_hash(&self) -> u64 {
+        zobrist_hash(self)
+    }
+
     pub fn get_available_moves(&self) -> Vec<Move> {
         self.possible_moves.clone()
     }
@@ -91,17 +160,43 @@ impl GameState {
             .map(|m| (m.generate_san(), m))
             .collect();
 
-        if let Some(requested_move) = possible_moves.remove(&san.to_string()) {
-            Some(self.make_move_inner(requested_move))
-        } else {
-            None
+        possible_moves
+            .remove(san)
+            .map(|requested_move| self.make_move_inner(requested_move))
+    }
+
+    ///Passes the turn without moving, for the search to see what the opponent would do with two
+    ///moves in a row
+    pub(crate) fn make_null_move(&self) -> Self {
+        let mut game_state = self.clone();
+        game_state.sans.push(String::from(NULL_MOVE_SAN));
+        //The position after passing can't repeat one from before it
+        game_state.previous_positions.clear();
+        game_state.en_passant_target = None;
+        game_state.next_turn();
+        let is_first_player_turn = game_state.is_first_player_turn;
+        let possible_moves = generate_moves(&mut game_state, is_first_player_turn);
+        GameState {
+            possible_moves,
+            ..game_state
         }
     }
 
     fn make_move_inner(&self, requested_move: &Move) -> Self {
         let mut game_state = self.clone();
         game_state.sans.push(requested_move.generate_san());
+        if requested_move.is_irreversible() {
+            game_state.previous_positions.clear();
+        } else {
+            game_state.previous_positions.push(self.zobrist_hash());
+        }
+        game_state.halfmove_clock = if requested_move.resets_halfmove_clock() {
+            0
+        } else {
+            self.halfmove_clock.saturating_add(1)
+        };
         resolve_move(requested_move, &mut game_state);
+        game_state.en_passant_target = en_passant_target(requested_move);
         let is_first_player_turn = game_state.is_first_player_turn;
         let possible_moves = generate_moves(&mut game_state, is_first_player_turn);
         GameState {
@@ -110,41 +205,18 @@ impl GameState {
         }
     }
 
-    pub(crate) fn determine_status(self) -> Game {
-        if self.possible_moves.is_empty() {
-            if self.is_check(self.is_first_player_turn) {
-                Game::Win {
-                    is_first_player_win: !self.is_first_player_turn(),
-                    state: self,
-                }
-            } else {
-                Game::Draw { state: self }
-            }
-        } else {
-            let mut is_first_player_turn = !self.is_first_player_turn;
-            let mut first_player_sans = vec![];
-            let mut second_player_sans = vec![];
-            for san in self.sans.clone().into_iter().rev() {
-                if is_first_player_turn {
-                    first_player_sans.push(san);
-                } else {
-                    second_player_sans.push(san);
-                }
-                is_first_player_turn = !is_first_player_turn;
-            }
-
-            if (!self.is_first_player_turn && self.is_fivefold_repetition(&first_player_sans))
-                || self.is_fivefold_repetition(&second_player_sans)
-                || self.turn_number - self.captured_pieces.last_capture_turn >= 75
-            {
-                Game::Draw { state: self }
-            } else {
-                Game::Ongoing { state: self }
-            }
-        }
+    ///The position has been seen before, since the last irreversible move
+    pub(crate) fn is_repetition(&self) -> bool {
+        let hash = self.zobrist_hash();
+        self.previous_positions.contains(&hash)
     }
 
-    pub(crate) fn is_fivefold_repetition(&self, first_player_sans: &Vec<String>) -> bool {
+    ///Fifty moves by each player without a pawn move or capture
+    pub(crate) fn is_fifty_move_draw(&self) -> bool {
+        self.halfmove_clock >= FIFTY_MOVE_RULE_PLIES
+    }
+
+    pub(crate) fn is_fivefold_repetition(&self, first_player_sans: &[String]) -> bool {
         first_player_sans.len() >= 5
             && first_player_sans[0] == first_player_sans[2]
             && first_player_sans[2] == first_player_sans[4]
@@ -162,6 +234,25 @@ impl Default for GameState {
     }
 }
 
+///The square passed over when a pawn moves two squares from its second rank, None for any other
+///move. Horde pawns can also move two squares from the first rank, but can't be taken en passant
+///after doing so.
+fn en_passant_target(requested_move: &Move) -> Option<Coordinate> {
+    match *requested_move {
+        Move::PawnMove(from, to)
+            if from.is_rank_2() && from.north().and_then(|c| c.north()) == Some(to) =>
+        {
+            from.north()
+        }
+        Move::PawnMove(from, to)
+            if from.is_rank_7() && from.south().and_then(|c| c.south()) == Some(to) =>
+        {
+            from.south()
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::moves::Move::PawnMove;
@@ -184,7 +275,7 @@ mod tests {
 
         game_state.next_turn();
 
-        assert_eq!(false, game_state.is_first_player_turn);
+        assert!(!game_state.is_first_player_turn);
     }
 
     #[test]
@@ -271,7 +362,7 @@ mod tests {
 
         let result = state.make_move(&legal_move);
 
-        assert_that!(matches!(result, Some(_)))
+        assert_that!(result.is_some())
     }
 
     #[test]
@@ -281,6 +372,114 @@ mod tests {
 
         let result = state.make_move(&illegal_move);
 
-        assert_that!(matches!(result, None))
+        assert_that!(result.is_none())
+    }
+
+    #[test]
+    fn null_move_passes_the_turn() {
+        let game_state = GameState::from_fen("3k4/8/8/8/8/8/8/R2K4 w - - 0 1");
+
+        let result = game_state.make_null_move();
+
+        assert_eq!("3k4/8/8/8/8/8/8/R2K4 b - - 0 1", result.generate_fen());
+        assert_eq!(game_state.board, result.board);
+        assert!(result
+            .possible_moves
+            .iter()
+            .all(|possible_move| possible_move.generate_san().starts_with('K')));
+    }
+
+    #[test]
+    fn null_move_removes_en_passant() {
+        let game_state = GameState::from_fen("3k4/8/8/8/2p5/8/1P6/3K4 w - - 0 1")
+            .make_move(&PawnMove(Coordinate::B2, Coordinate::B4))
+            .unwrap();
+
+        let result = game_state.make_null_move().make_null_move();
+
+        assert!(!result
+            .possible_moves
+            .iter()
+            .any(|possible_move| matches!(possible_move, Move::EnPassant(..))));
+    }
+
+    #[test]
+    fn pawns_and_king_are_not_non_pawn_material() {
+        let game_state = GameState::from_fen("3k4/3p4/8/8/8/8/3P4/R2K4 w - - 0 1");
+
+        assert!(game_state.has_non_pawn_material(true));
+        assert!(!game_state.has_non_pawn_material(false));
+    }
+
+    #[test]
+    fn returning_to_a_position_is_a_repetition() {
+        let game_state = GameState::from_fen("3k4/8/8/8/8/8/8/R2K4 w - - 0 1");
+
+        let result = ["Ra2", "Kc8", "Ra1", "Kd8"]
+            .iter()
+            .fold(game_state, |state, san| state.make_move_san(san).unwrap());
+
+        assert!(result.is_repetition());
+    }
+
+    #[test]
+    fn new_position_is_not_a_repetition() {
+        let game_state = GameState::from_fen("3k4/8/8/8/8/8/8/R2K4 w - - 0 1");
+
+        let result = ["Ra2", "Kc8", "Ra1"]
+            .iter()
+            .fold(game_state, |state, san| state.make_move_san(san).unwrap());
+
+        assert!(!result.is_repetition());
+        assert_eq!(3, result.previous_positions.len());
+    }
+
+    #[test]
+    fn irreversible_move_forgets_earlier_positions() {
+        let game_state = GameState::from_fen("3k4/8/8/8/8/8/3P4/R2K4 w - - 0 1");
+
+        let result = ["Ra2", "Kc8", "d4"]
+            .iter()
+            .fold(game_state, |state, san| state.make_move_san(san).unwrap());
+
+        assert!(result.previous_positions.is_empty());
+    }
+
+    #[test]
+    fn fifty_moves_without_pawn_move_or_capture_is_a_draw() {
+        let game_state = GameState::from_fen("3k4/8/8/8/8/8/8/R2K4 w - - 99 80");
+        assert!(!game_state.is_fifty_move_draw());
+
+        let result = game_state.make_move_san("Ra2").unwrap();
+
+        assert!(result.is_fifty_move_draw());
+    }
+
+    #[test]
+    fn castling_does_not_reset_fifty_move_count() {
+        let game_state = GameState::from_fen("3k4/8/8/8/8/8/8/4K2R w K - 99 80");
+
+        let result = game_state.make_move_san("O-O").unwrap();
+
+        assert!(result.is_fifty_move_draw());
+        assert!(result.previous_positions.is_empty());
+    }
+
+    #[test]
+    fn pawn_move_resets_fifty_move_count() {
+        let game_state = GameState::from_fen("3k4/8/8/8/8/8/3P4/R2K4 w - - 99 80");
+
+        let result = game_state.make_move_san("d4").unwrap();
+
+        assert_eq!(0, result.halfmove_clock);
+    }
+
+    #[test]
+    fn null_move_keeps_fifty_move_count() {
+        let game_state = GameState::from_fen("3k4/8/8/8/8/8/8/R2K4 w - - 42 80");
+
+        let result = game_state.make_null_move();
+
+        assert_eq!(42, result.halfmove_clock);
     }
 }
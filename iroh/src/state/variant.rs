@@ -0,0 +1,131 @@
+use crate::state::STARTING_POSITION_FEN;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+const CRAZYHOUSE_STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1";
+const THREE_CHECK_STARTING_FEN: &str =
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 +0+0";
+const ANTICHESS_STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1";
+const HORDE_STARTING_FEN: &str =
+    "rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1";
+const RACING_KINGS_STARTING_FEN: &str = "8/8/8/8/8/8/krbnNBRK/qrbnNBRQ w - - 0 1";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Variant {
+    #[default]
+    Standard,
+    Crazyhouse,
+    Atomic,
+    Antichess,
+    ThreeCheck,
+    KingOfTheHill,
+    Horde,
+    RacingKings,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 8] = [
+        Variant::Standard,
+        Variant::Crazyhouse,
+        Variant::Atomic,
+        Variant::Antichess,
+        Variant::ThreeCheck,
+        Variant::KingOfTheHill,
+        Variant::Horde,
+        Variant::RacingKings,
+    ];
+
+    pub fn starting_fen(&self) -> &'static str {
+        match self {
+            Variant::Standard | Variant::Atomic | Variant::KingOfTheHill => STARTING_POSITION_FEN,
+            Variant::Crazyhouse => CRAZYHOUSE_STARTING_FEN,
+            Variant::Antichess => ANTICHESS_STARTING_FEN,
+            Variant::ThreeCheck => THREE_CHECK_STARTING_FEN,
+            Variant::Horde => HORDE_STARTING_FEN,
+            Variant::RacingKings => RACING_KINGS_STARTING_FEN,
+        }
+    }
+}
+
+impl Display for Variant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Variant::Standard => write!(f, "standard"),
+            Variant::Crazyhouse => write!(f, "crazyhouse"),
+            Variant::Atomic => write!(f, "atomic"),
+            Variant::Antichess => write!(f, "antichess"),
+            Variant::ThreeCheck => write!(f, "threecheck"),
+            Variant::KingOfTheHill => write!(f, "kingofthehill"),
+            Variant::Horde => write!(f, "horde"),
+            Variant::RacingKings => write!(f, "racingkings"),
+        }
+    }
+}
+
+impl FromStr for Variant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "standard" | "chess" => Ok(Variant::Standard),
+            "crazyhouse" => Ok(Variant::Crazyhouse),
+            "atomic" => Ok(Variant::Atomic),
+            "antichess" | "losing" => Ok(Variant::Antichess),
+            "threecheck" | "three-check" | "3check" => Ok(Variant::ThreeCheck),
+            "kingofthehill" | "king-of-the-hill" | "koth" => Ok(Variant::KingOfTheHill),
+            "horde" => Ok(Variant::Horde),
+            "racingkings" | "racing-kings" => Ok(Variant::RacingKings),
+            _ => Err(format!("Unknown variant: {s}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_variant_is_standard() {
+        assert_eq!(Variant::Standard, Variant::default());
+    }
+
+    #[test]
+    fn every_variant_parses_from_its_name() {
+        for variant in Variant::ALL {
+            assert_eq!(Ok(variant), variant.to_string().parse::<Variant>());
+        }
+    }
+
+    #[test]
+    fn parse_variant_from_name() {
+        assert_eq!(Ok(Variant::Crazyhouse), "Crazyhouse".parse::<Variant>());
+    }
+
+    #[test]
+    fn antichess_starts_without_castling_rights() {
+        let result = Variant::Antichess.starting_fen();
+
+        assert_eq!(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1",
+            result
+        );
+    }
+
+    #[test]
+    fn given_unknown_name_parse_variant_fails() {
+        assert!("banana".parse::<Variant>().is_err());
+    }
+
+    #[test]
+    fn parse_variant_from_alias() {
+        assert_eq!(Ok(Variant::ThreeCheck), "3check".parse::<Variant>());
+        assert_eq!(Ok(Variant::KingOfTheHill), "koth".parse::<Variant>());
+    }
+
+    #[test]
+    fn display_variant_round_trips_through_parse() {
+        let result = Variant::Crazyhouse.to_string().parse::<Variant>();
+
+        assert_eq!(Ok(Variant::Crazyhouse), result);
+    }
+}
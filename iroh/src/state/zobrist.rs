@@ -0,0 +1,194 @@
+use crate::moves::Move;
+use crate::state::tile::Tile;
+use crate::state::variant::Variant;
+use crate::state::GameState;
+
+const PIECE_KINDS: usize = 13;
+const TILES: usize = 128;
+const MAX_POCKET_COUNT: usize = 16;
+const MAX_CHECKS_GIVEN: usize = 3;
+
+const PIECE_KEYS_START: usize = 0;
+const EN_PASSANT_KEYS_START: usize = PIECE_KEYS_START + PIECE_KINDS * TILES;
+const POCKET_KEYS_START: usize = EN_PASSANT_KEYS_START + TILES;
+const CHECKS_GIVEN_KEYS_START: usize = POCKET_KEYS_START + PIECE_KINDS * (MAX_POCKET_COUNT + 1);
+const CASTLING_KEYS_START: usize = CHECKS_GIVEN_KEYS_START + 2 * (MAX_CHECKS_GIVEN + 1);
+const SECOND_PLAYER_TURN_KEY: usize = CASTLING_KEYS_START + 4;
+const N_KEYS: usize = SECOND_PLAYER_TURN_KEY + 1;
+
+const KEYS: [u64; N_KEYS] = generate_keys();
+
+///Random numbers from the SplitMix64 generator, fixed at compile time so hashes are the same on
+///every run
+const fn generate_keys() -> [u64; N_KEYS] {
+    let mut keys = [0; N_KEYS];
+    let mut seed: u64 = 0x1A0B_5EED;
+    let mut index = 0;
+    while index < N_KEYS {
+        seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut key = seed;
+        key = (key ^ (key >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        key = (key ^ (key >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        keys[index] = key ^ (key >> 31);
+        index += 1;
+    }
+    keys
+}
+
+fn piece_kind(tile: Tile) -> usize {
+    (*tile + 6) as usize
+}
+
+///Identifies a position, so positions reached by different move orders share a hash.
+///Everything that affects which moves are legal is included.
+pub fn zobrist_hash(game_state: &GameState) -> u64 {
+    let mut hash = 0;
+
+    for is_first_player in [true, false] {
+        for (tile, coordinate) in game_state
+            .board
+            .get_all_pieces_belonging_to_player(is_first_player)
+        {
+            hash ^= KEYS[PIECE_KEYS_START + piece_kind(tile) * TILES + coordinate.as_usize()];
+        }
+    }
+
+    for possible_move in game_state.possible_moves.iter() {
+        if let Move::EnPassant(_, to) = possible_move {
+            hash ^= KEYS[EN_PASSANT_KEYS_START + to.as_usize()];
+        }
+    }
+
+    if game_state.variant == Variant::Crazyhouse {
+        hash ^= pocket_hash(game_state, true) ^ pocket_hash(game_state, false);
+    }
+
+    if game_state.variant == Variant::ThreeCheck {
+        let first_player_checks =
+            (game_state.first_player_checks_given as usize).min(MAX_CHECKS_GIVEN);
+        let second_player_checks =
+            (game_state.second_player_checks_given as usize).min(MAX_CHECKS_GIVEN);
+        hash ^= KEYS[CHECKS_GIVEN_KEYS_START + first_player_checks];
+        hash ^= KEYS[CHECKS_GIVEN_KEYS_START + MAX_CHECKS_GIVEN + 1 + second_player_checks];
+    }
+
+    let castling_rights = [
+        game_state.first_player_can_castle_kingside,
+        game_state.first_player_can_castle_queenside,
+        game_state.second_player_can_castle_kingside,
+        game_state.second_player_can_castle_queenside,
+    ];
+    for (index, can_castle) in castling_rights.into_iter().enumerate() {
+        if can_castle {
+            hash ^= KEYS[CASTLING_KEYS_START + index];
+        }
+    }
+
+    if !game_state.is_first_player_turn {
+        hash ^= KEYS[SECOND_PLAYER_TURN_KEY];
+    }
+
+    hash
+}
+
+fn pocket_hash(game_state: &GameState, is_first_player: bool) -> u64 {
+    let mut counts = [0_usize; PIECE_KINDS];
+    for tile in game_state.captured_pieces.pocket(is_first_player) {
+        counts[piece_kind(tile)] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .filter(|(_, count)| *count > 0)
+        .map(|(kind, count)| {
+            KEYS[POCKET_KEYS_START + kind * (MAX_POCKET_COUNT + 1) + count.min(MAX_POCKET_COUNT)]
+        })
+        .fold(0, |hash, key| hash ^ key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_are_unique() {
+        let mut keys = KEYS.to_vec();
+        keys.sort();
+        keys.dedup();
+
+        assert_eq!(N_KEYS, keys.len());
+    }
+
+    #[test]
+    fn transposed_positions_have_same_hash() {
+        let state = GameState::new();
+        let knights_first = state
+            .make_move_san("Nf3")
+            .and_then(|state| state.make_move_san("Nf6"))
+            .and_then(|state| state.make_move_san("Nc3"))
+            .unwrap();
+        let knights_swapped = state
+            .make_move_san("Nc3")
+            .and_then(|state| state.make_move_san("Nf6"))
+            .and_then(|state| state.make_move_san("Nf3"))
+            .unwrap();
+
+        assert_eq!(zobrist_hash(&knights_first), zobrist_hash(&knights_swapped));
+    }
+
+    #[test]
+    fn player_to_move_changes_hash() {
+        let first_player_turn = GameState::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        let second_player_turn = GameState::from_fen("4k3/8/8/8/8/8/8/4K3 b - - 0 1");
+
+        assert_ne!(
+            zobrist_hash(&first_player_turn),
+            zobrist_hash(&second_player_turn)
+        );
+    }
+
+    #[test]
+    fn castling_rights_change_hash() {
+        let can_castle = GameState::from_fen("4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+        let cannot_castle = GameState::from_fen("4k3/8/8/8/8/8/8/4K2R w - - 0 1");
+
+        assert_ne!(zobrist_hash(&can_castle), zobrist_hash(&cannot_castle));
+    }
+
+    #[test]
+    fn pieces_on_different_tiles_change_hash() {
+        let state = GameState::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        let moved = GameState::from_fen("4k3/8/8/8/8/8/4K3/8 w - - 0 1");
+
+        assert_ne!(zobrist_hash(&state), zobrist_hash(&moved));
+    }
+
+    #[test]
+    fn crazyhouse_pocket_changes_hash() {
+        let empty_pocket = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+        let knight_in_pocket = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3[N] w - - 0 1",
+            Variant::Crazyhouse,
+        );
+
+        assert_ne!(zobrist_hash(&empty_pocket), zobrist_hash(&knight_in_pocket));
+    }
+
+    #[test]
+    fn checks_given_change_hash() {
+        let no_checks = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3 w - - 0 1 +0+0",
+            Variant::ThreeCheck,
+        );
+        let one_check = GameState::from_fen_with_variant(
+            "4k3/8/8/8/8/8/8/4K3 w - - 0 1 +1+0",
+            Variant::ThreeCheck,
+        );
+
+        assert_ne!(zobrist_hash(&no_checks), zobrist_hash(&one_check));
+    }
+}
@@ -84,6 +84,10 @@ chess_test! {
         "1. d8=R 1/2-1/2","3R4/8/8/8/8/8/8/8 b - - 0 1"}
     {promote_second_player_pawn_to_queen @ "8/8/3P4/8/8/8/3p4/8 w - - 0 1",["d7","d1=Q"],
         "1. d7 d1=Q *","8/3P4/8/8/8/8/8/3q4 w - - 0 1"}
+    {promote_by_capturing @ "2rk4/1P6/8/8/8/8/8/3K4 w - - 0 1",["bxc8=Q"],
+        "1. bxc8=Q *","2Qk4/8/8/8/8/8/8/3K4 b - - 0 1"}
+    {second_player_promote_by_capturing @ "3k4/8/8/8/8/8/6p1/3K3R b - - 0 1",["gxh1=N"],
+        "1. gxh1=N *","3k4/8/8/8/8/8/8/3K3n w - - 0 1"}
 }
 
 #[test]
@@ -258,7 +262,7 @@ fn given_five_fold_repetition_from_first_player_should_automatically_draw() {
 fn given_seventy_five_turns_without_pawn_move_or_capture_should_automatically_draw() {
     let mut game = Game::from_fen("4k3/3rr3/8/8/8/8/3RR3/4K3 w - - 0 1");
 
-    let moves = vec!["Rc2", "Rc7", "Rb2", "Rb7", "Ra2", "Ra7"];
+    let moves = ["Rc2", "Rc7", "Rb2", "Rb7", "Ra2", "Ra7"];
     let mut index = 0;
 
     for _ in 0..74 {
@@ -274,7 +278,7 @@ fn given_seventy_five_turns_without_pawn_move_or_capture_should_automatically_dr
 
 #[test]
 fn en_passant() {
-    let mut game = Game::from_fen("3k4/2p5/8/1P6/8/8/8/3K4 b - - 0 1");
+    let game = Game::from_fen("3k4/2p5/8/1P6/8/8/8/3K4 b - - 0 1");
 
     let game = game.make_move_san("c5");
     let game = game.make_move_san("bxc6");
@@ -0,0 +1,90 @@
+use iroh::game::Game;
+use iroh::state::variant::Variant;
+
+#[test]
+fn new_antichess_game_has_no_castling_rights() {
+    let game = Game::with_variant(Variant::Antichess);
+
+    let result = game.generate_fen().unwrap();
+
+    assert_eq!(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1",
+        result
+    );
+}
+
+#[test]
+fn capture_is_compulsory() {
+    let mut game = Game::with_variant(Variant::Antichess);
+
+    game = game.make_move_san("e3");
+    game = game.make_move_san("b5");
+    let non_capture = game.make_move_san("Nf3");
+    let capture = game.make_move_san("Bxb5");
+
+    assert!(non_capture.is_err());
+    assert!(!capture.is_err());
+}
+
+#[test]
+fn king_can_be_captured() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/8/8/N3K2r b - - 0 1", Variant::Antichess);
+
+    let result = game.make_move_san("Rxe1");
+
+    assert_eq!(
+        "4k3/8/8/8/8/8/8/N3r3 w - - 0 1",
+        result.generate_fen().unwrap()
+    );
+}
+
+#[test]
+fn losing_all_pieces_wins() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/8/8/4K2r b - - 0 1", Variant::Antichess);
+
+    let result = game.make_move_san("Rxe1");
+
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: true,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn being_stalemated_wins() {
+    let game = Game::from_fen_with_variant("8/8/8/8/p7/8/P7/8 b - - 0 1", Variant::Antichess);
+
+    let result = game.make_move_san("a3");
+
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: true,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn pawn_can_promote_to_king() {
+    let game = Game::from_fen_with_variant("8/P7/8/8/8/8/8/7k w - - 0 1", Variant::Antichess);
+
+    let result = game.make_move_san("a8=K");
+
+    assert_eq!(
+        "K7/8/8/8/8/8/8/7k b - - 0 1",
+        result.generate_fen().unwrap()
+    );
+}
+
+#[test]
+fn checkmate_does_not_end_the_game() {
+    let game = Game::from_fen_with_variant("5k2/R7/8/8/8/8/8/1R2K3 w - - 0 1", Variant::Antichess);
+
+    let result = game.make_move_san("Rb8");
+
+    assert!(matches!(result, Game::Ongoing { .. }));
+}
@@ -0,0 +1,121 @@
+use iroh::game::Game;
+use iroh::state::variant::Variant;
+
+#[test]
+fn capture_explodes_capturing_piece_and_neighbouring_pieces() {
+    let game =
+        Game::from_fen_with_variant("4k3/8/2pnb3/2RqP3/2PQn3/8/8/4K3 w - - 0 1", Variant::Atomic);
+
+    let result = game.make_move_san("Qxd5");
+
+    assert_eq!(
+        "4k3/8/2p5/4P3/2P5/8/8/4K3 b - - 0 1",
+        result.generate_fen().unwrap()
+    );
+}
+
+#[test]
+fn explosion_removes_castling_rights_of_exploded_rook() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/4N3/6p1/4K2R w K - 0 1", Variant::Atomic);
+
+    let result = game.make_move_san("Nxg2");
+
+    assert_eq!(
+        "4k3/8/8/8/8/8/8/4K3 b - - 0 1",
+        result.generate_fen().unwrap()
+    );
+}
+
+#[test]
+fn exploding_enemy_king_wins() {
+    let game = Game::from_fen_with_variant("4k3/4q3/8/8/8/8/8/4RK2 w - - 0 1", Variant::Atomic);
+
+    let result = game.make_move_san("Rxe7");
+
+    assert_eq!("1. Rxe7 1-0", result.generate_pgn().unwrap());
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: true,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn exploding_enemy_king_is_legal_even_when_in_check() {
+    let game = Game::from_fen_with_variant("3rk3/8/8/8/8/8/3Q4/r2K4 w - - 0 1", Variant::Atomic);
+
+    let result = game.make_move_san("Qxd8");
+
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: true,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn second_player_can_win_by_explosion() {
+    let game = Game::from_fen_with_variant("4k3/8/8/q7/8/8/3N4/4K3 b - - 0 1", Variant::Atomic);
+
+    let result = game.make_move_san("Qxd2");
+
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: false,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn king_cannot_capture() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/8/3p4/4K3 w - - 0 1", Variant::Atomic);
+
+    let result = game.make_move_san("Kxd2");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn cannot_capture_next_to_own_king() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/8/3p4/3RK3 w - - 0 1", Variant::Atomic);
+
+    let result = game.make_move_san("Rxd2");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn kings_can_touch() {
+    let game = Game::from_fen_with_variant("8/8/8/8/8/3k4/8/4K3 w - - 0 1", Variant::Atomic);
+    let standard_game = Game::from_fen("8/8/8/8/8/3k4/8/4K3 w - - 0 1");
+
+    let result = game.make_move_san("Ke2");
+    let standard_result = standard_game.make_move_san("Ke2");
+
+    assert!(!result.is_err());
+    assert!(standard_result.is_err());
+}
+
+#[test]
+fn touching_kings_are_not_in_check() {
+    let game = Game::from_fen_with_variant("4r3/8/8/8/8/3k4/8/4K3 w - - 0 1", Variant::Atomic);
+
+    let result = game.make_move_san("Ke2");
+
+    assert!(!result.is_err());
+}
+
+#[test]
+fn checkmate_still_wins() {
+    let game = Game::from_fen_with_variant("5k2/R7/8/8/8/8/8/1R2K3 w - - 0 1", Variant::Atomic);
+
+    let result = game.make_move_san("Rb8");
+
+    assert_eq!("1. Rb8 1-0", result.generate_pgn().unwrap());
+}
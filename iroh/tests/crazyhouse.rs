@@ -0,0 +1,159 @@
+use iroh::game::Game;
+use iroh::state::tile::Tile;
+use iroh::state::variant::Variant;
+
+#[test]
+fn new_crazyhouse_game_has_empty_pockets() {
+    let game = Game::with_variant(Variant::Crazyhouse);
+
+    let result = game.generate_fen().unwrap();
+
+    assert_eq!(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1",
+        result
+    );
+}
+
+#[test]
+fn captured_piece_goes_into_capturers_pocket() {
+    let mut game = Game::with_variant(Variant::Crazyhouse);
+
+    game = game.make_move_san("e4");
+    game = game.make_move_san("d5");
+    game = game.make_move_san("exd5");
+
+    assert_eq!(
+        "rnbqkbnr/ppp1pppp/8/3P4/8/8/PPPP1PPP/RNBQKBNR[P] b KQkq - 0 1",
+        game.generate_fen().unwrap()
+    );
+}
+
+#[test]
+fn captured_piece_can_be_dropped() {
+    let mut game = Game::with_variant(Variant::Crazyhouse);
+
+    game = game.make_move_san("e4");
+    game = game.make_move_san("d5");
+    game = game.make_move_san("exd5");
+    game = game.make_move_san("Qxd5");
+    game = game.make_move_san("P@e4");
+
+    assert_eq!(
+        "1. e4 d5 2. exd5 Qxd5 3. P@e4 *",
+        game.generate_pgn().unwrap()
+    );
+    assert_eq!(
+        "rnb1kbnr/ppp1pppp/8/3q4/4P3/8/PPPP1PPP/RNBQKBNR[p] b KQkq - 0 1",
+        game.generate_fen().unwrap()
+    );
+}
+
+#[test]
+fn cannot_drop_piece_that_is_not_in_pocket() {
+    let game =
+        Game::from_fen_with_variant("4k3/8/8/8/8/8/8/4K3[Nq] w - - 0 1", Variant::Crazyhouse);
+
+    let result = game.make_move_san("Q@d4");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn cannot_drop_in_standard_chess() {
+    let game = Game::from_fen("4k3/8/8/8/8/8/8/4K3[N] w - - 0 1");
+
+    let result = game.make_move_san("N@f3");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn cannot_drop_onto_occupied_tile() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/8/8/4K3[N] w - - 0 1", Variant::Crazyhouse);
+
+    let result = game.make_move_san("N@e1");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn cannot_drop_pawn_on_back_rank() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/8/8/4K3[P] w - - 0 1", Variant::Crazyhouse);
+
+    let first_rank = game.make_move_san("P@a1");
+    let last_rank = game.make_move_san("P@a8");
+
+    assert!(first_rank.is_err());
+    assert!(last_rank.is_err());
+}
+
+#[test]
+fn drop_can_block_check() {
+    let game =
+        Game::from_fen_with_variant("4k3/8/8/8/4r3/8/8/4K3[B] w - - 0 1", Variant::Crazyhouse);
+
+    let blocking_drop = game.make_move_san("B@e2");
+    let non_blocking_drop = game.make_move_san("B@a2");
+
+    assert!(!blocking_drop.is_err());
+    assert!(non_blocking_drop.is_err());
+}
+
+#[test]
+fn drop_can_give_checkmate() {
+    let game =
+        Game::from_fen_with_variant("6rk/6pp/8/8/8/8/8/4K3[N] w - - 0 1", Variant::Crazyhouse);
+
+    let result = game.make_move_san("N@f7");
+
+    assert_eq!("1. N@f7 1-0", result.generate_pgn().unwrap());
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: true,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn captured_promoted_piece_reverts_to_pawn() {
+    let mut game =
+        Game::from_fen_with_variant("3qk3/2P5/8/8/8/8/8/4K3[] w - - 0 1", Variant::Crazyhouse);
+
+    game = game.make_move_san("c8=Q");
+    assert_eq!(
+        "2Q~qk3/8/8/8/8/8/8/4K3[] b - - 0 1",
+        game.generate_fen().unwrap()
+    );
+    game = game.make_move_san("Qxc8");
+
+    assert_eq!(
+        vec![Tile::SECOND_PAWN],
+        game.captured_pieces().unwrap().pocket(false)
+    );
+    assert_eq!(
+        "2q1k3/8/8/8/8/8/8/4K3[p] w - - 0 1",
+        game.generate_fen().unwrap()
+    );
+}
+
+#[test]
+fn promoted_piece_parsed_from_fen_reverts_to_pawn_when_captured() {
+    let game =
+        Game::from_fen_with_variant("4k3/8/8/8/8/8/4q3/3RK3[] w - - 0 1", Variant::Crazyhouse);
+    let promoted_game =
+        Game::from_fen_with_variant("4k3/8/8/8/8/8/4q~3/3RK3[] w - - 0 1", Variant::Crazyhouse);
+
+    let result = game.make_move_san("Kxe2");
+    let promoted_result = promoted_game.make_move_san("Kxe2");
+
+    assert_eq!(
+        "4k3/8/8/8/8/8/4K3/3R4[Q] b - - 0 1",
+        result.generate_fen().unwrap()
+    );
+    assert_eq!(
+        "4k3/8/8/8/8/8/4K3/3R4[P] b - - 0 1",
+        promoted_result.generate_fen().unwrap()
+    );
+}
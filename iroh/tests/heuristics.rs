@@ -79,3 +79,18 @@ fn taking_rook_beats_returning_to_e5() {
 
     assert_that!(better_value > worse_value, otherwise format!("Better position evaluates to {better_value}, but worse evaluated to {worse_value}"));
 }
+
+#[test]
+fn heuristics_can_be_shared_between_threads() {
+    let mut state = GameState::new();
+    let heuristics = Heuristics::new();
+
+    let result = std::thread::scope(|scope| {
+        scope
+            .spawn(|| heuristics.evaluate(&mut state))
+            .join()
+            .unwrap()
+    });
+
+    assert_eq!(0, result)
+}
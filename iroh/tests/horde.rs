@@ -0,0 +1,73 @@
+use iroh::game::Game;
+use iroh::state::variant::Variant;
+
+#[test]
+fn new_horde_game_has_thirty_six_pawns() {
+    let game = Game::with_variant(Variant::Horde);
+
+    let result = game.generate_fen().unwrap();
+
+    assert_eq!(
+        "rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1",
+        result
+    );
+    assert_eq!(36, result.chars().filter(|c| *c == 'P').count());
+}
+
+#[test]
+fn horde_opening_moves_are_playable() {
+    let mut game = Game::with_variant(Variant::Horde);
+
+    game = game.make_move_san("f6");
+    game = game.make_move_san("exf6");
+
+    assert!(matches!(game, Game::Ongoing { .. }));
+}
+
+#[test]
+fn pawn_on_first_rank_can_move_two_tiles() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/8/8/P7 w - - 0 1", Variant::Horde);
+    let standard_game = Game::from_fen("4k3/8/8/8/8/8/8/P3K3 w - - 0 1");
+
+    let result = game.make_move_san("a3");
+    let standard_result = standard_game.make_move_san("a3");
+
+    assert!(!result.is_err());
+    assert!(standard_result.is_err());
+}
+
+#[test]
+fn pawn_moving_two_tiles_from_first_rank_cannot_be_taken_en_passant() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/1p6/8/P7 w - - 0 1", Variant::Horde);
+
+    let game = game.make_move_san("a3").unwrap_if_ongoing();
+
+    assert!(!game
+        .possible_moves
+        .iter()
+        .any(|possible_move| possible_move.generate_san() == "bxa2"));
+}
+
+#[test]
+fn capturing_every_white_piece_wins_for_black() {
+    let game = Game::from_fen_with_variant("4k3/8/8/8/8/8/8/P6r b - - 0 1", Variant::Horde);
+
+    let result = game.make_move_san("Rxa1");
+
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: false,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn checkmating_black_wins_for_white() {
+    let game = Game::from_fen_with_variant("k7/1nP5/PPP5/8/8/8/8/8 w - - 0 1", Variant::Horde);
+
+    let result = game.make_move_san("axb7");
+
+    assert_eq!("1. axb7 1-0", result.generate_pgn().unwrap());
+}
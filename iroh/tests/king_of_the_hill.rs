@@ -0,0 +1,65 @@
+use iroh::game::Game;
+use iroh::search::search;
+use iroh::state::variant::Variant;
+
+#[test]
+fn king_reaching_centre_wins() {
+    let game = Game::from_fen_with_variant("k7/8/8/8/8/5K2/8/8 w - - 0 1", Variant::KingOfTheHill);
+
+    let result = game.make_move_san("Ke4");
+
+    assert_eq!("1. Ke4 1-0", result.generate_pgn().unwrap());
+}
+
+#[test]
+fn second_player_king_reaching_centre_wins() {
+    let game = Game::from_fen_with_variant("8/8/2k5/8/8/8/8/K7 b - - 0 1", Variant::KingOfTheHill);
+
+    let result = game.make_move_san("Kd5");
+
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: false,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn king_cannot_move_onto_attacked_centre_tile() {
+    let game =
+        Game::from_fen_with_variant("k2r4/8/8/8/8/4K3/8/8 w - - 0 1", Variant::KingOfTheHill);
+
+    let result = game.make_move_san("Kd4");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn other_pieces_reaching_centre_do_not_win() {
+    let game = Game::from_fen_with_variant("k7/8/8/8/8/8/8/3RK3 w - - 0 1", Variant::KingOfTheHill);
+
+    let result = game.make_move_san("Rd4");
+
+    assert!(matches!(result, Game::Ongoing { .. }));
+}
+
+#[test]
+fn king_reaching_centre_does_not_end_standard_game() {
+    let game = Game::from_fen("k7/8/8/8/8/5K2/8/8 w - - 0 1");
+
+    let result = game.make_move_san("Ke4");
+
+    assert!(matches!(result, Game::Ongoing { .. }));
+}
+
+#[test]
+fn search_walks_king_onto_hill() {
+    let mut game =
+        Game::from_fen_with_variant("k7/8/8/8/8/5K2/8/8 w - - 0 1", Variant::KingOfTheHill);
+
+    let result = search(&mut game).unwrap();
+
+    assert_eq!("Ke4", result.best_move);
+}
@@ -27,14 +27,8 @@ fn generate_first_turn_pawn_moves(from: Coordinate) {
     assert_that!(
         &available_moves,
         contains_subset(vec![
-            Move::PawnMove {
-                0: from,
-                1: one_ahead
-            },
-            Move::PawnMove {
-                0: from,
-                1: two_ahead
-            },
+            Move::PawnMove(from, one_ahead),
+            Move::PawnMove(from, two_ahead),
         ])
     );
 }
@@ -48,46 +42,14 @@ fn generate_moves_for_knight() {
     assert_that!(
         &available_moves,
         contains_in_any_order(vec![
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::C4,
-                2: Tile::FIRST_KNIGHT
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::C6,
-                2: Tile::FIRST_KNIGHT
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D3,
-                2: Tile::FIRST_KNIGHT
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D7,
-                2: Tile::FIRST_KNIGHT
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::G4,
-                2: Tile::FIRST_KNIGHT
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::G6,
-                2: Tile::FIRST_KNIGHT
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F3,
-                2: Tile::FIRST_KNIGHT
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F7,
-                2: Tile::FIRST_KNIGHT
-            },
+            Move::RegularMove(Coordinate::E5, Coordinate::C4, Tile::FIRST_KNIGHT),
+            Move::RegularMove(Coordinate::E5, Coordinate::C6, Tile::FIRST_KNIGHT),
+            Move::RegularMove(Coordinate::E5, Coordinate::D3, Tile::FIRST_KNIGHT),
+            Move::RegularMove(Coordinate::E5, Coordinate::D7, Tile::FIRST_KNIGHT),
+            Move::RegularMove(Coordinate::E5, Coordinate::G4, Tile::FIRST_KNIGHT),
+            Move::RegularMove(Coordinate::E5, Coordinate::G6, Tile::FIRST_KNIGHT),
+            Move::RegularMove(Coordinate::E5, Coordinate::F3, Tile::FIRST_KNIGHT),
+            Move::RegularMove(Coordinate::E5, Coordinate::F7, Tile::FIRST_KNIGHT),
         ])
     );
 }
@@ -101,16 +63,8 @@ fn knight_moves_do_not_go_off_edge_of_board() {
     assert_that!(
         &available_moves,
         contains_in_any_order(vec![
-            Move::RegularMove {
-                0: Coordinate::A1,
-                1: Coordinate::B3,
-                2: Tile::FIRST_KNIGHT
-            },
-            Move::RegularMove {
-                0: Coordinate::A1,
-                1: Coordinate::C2,
-                2: Tile::FIRST_KNIGHT
-            },
+            Move::RegularMove(Coordinate::A1, Coordinate::B3, Tile::FIRST_KNIGHT),
+            Move::RegularMove(Coordinate::A1, Coordinate::C2, Tile::FIRST_KNIGHT),
         ])
     );
 }
@@ -124,46 +78,14 @@ fn generate_moves_for_king() {
     assert_that!(
         &available_moves,
         contains_in_any_order(vec![
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D4,
-                2: Tile::FIRST_KING
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D5,
-                2: Tile::FIRST_KING
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D6,
-                2: Tile::FIRST_KING
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E4,
-                2: Tile::FIRST_KING
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E6,
-                2: Tile::FIRST_KING
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F4,
-                2: Tile::FIRST_KING
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F5,
-                2: Tile::FIRST_KING
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F6,
-                2: Tile::FIRST_KING
-            },
+            Move::RegularMove(Coordinate::E5, Coordinate::D4, Tile::FIRST_KING),
+            Move::RegularMove(Coordinate::E5, Coordinate::D5, Tile::FIRST_KING),
+            Move::RegularMove(Coordinate::E5, Coordinate::D6, Tile::FIRST_KING),
+            Move::RegularMove(Coordinate::E5, Coordinate::E4, Tile::FIRST_KING),
+            Move::RegularMove(Coordinate::E5, Coordinate::E6, Tile::FIRST_KING),
+            Move::RegularMove(Coordinate::E5, Coordinate::F4, Tile::FIRST_KING),
+            Move::RegularMove(Coordinate::E5, Coordinate::F5, Tile::FIRST_KING),
+            Move::RegularMove(Coordinate::E5, Coordinate::F6, Tile::FIRST_KING),
         ])
     );
 }
@@ -177,76 +99,20 @@ fn generate_moves_for_rook() {
     assert_that!(
         &available_moves,
         contains_in_any_order(vec![
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E1,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E2,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E3,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E4,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E6,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E7,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E8,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::A5,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::B5,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::C5,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D5,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F5,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::G5,
-                2: Tile::FIRST_ROOK
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::H5,
-                2: Tile::FIRST_ROOK
-            },
+            Move::RegularMove(Coordinate::E5, Coordinate::E1, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::E2, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::E3, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::E4, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::E6, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::E7, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::E8, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::A5, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::B5, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::C5, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::D5, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::F5, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::G5, Tile::FIRST_ROOK),
+            Move::RegularMove(Coordinate::E5, Coordinate::H5, Tile::FIRST_ROOK),
         ])
     );
 }
@@ -260,71 +126,19 @@ fn generate_moves_for_bishop() {
     assert_that!(
         &available_moves,
         contains_in_any_order(vec![
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::A1,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::B2,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::C3,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D4,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F6,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::G7,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::H8,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::H2,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::G3,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F4,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D6,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::C7,
-                2: Tile::FIRST_BISHOP
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::B8,
-                2: Tile::FIRST_BISHOP
-            },
+            Move::RegularMove(Coordinate::E5, Coordinate::A1, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::B2, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::C3, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::D4, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::F6, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::G7, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::H8, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::H2, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::G3, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::F4, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::D6, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::C7, Tile::FIRST_BISHOP),
+            Move::RegularMove(Coordinate::E5, Coordinate::B8, Tile::FIRST_BISHOP),
         ])
     );
 }
@@ -338,141 +152,33 @@ fn generate_moves_for_queen() {
     assert_that!(
         &available_moves,
         contains_in_any_order(vec![
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::A1,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::B2,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::C3,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D4,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F6,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::G7,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::H8,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::H2,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::G3,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F4,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D6,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::B8,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::C7,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E1,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E2,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E3,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E4,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E6,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E7,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::E8,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::A5,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::B5,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::C5,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::D5,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::F5,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::G5,
-                2: Tile::FIRST_QUEEN
-            },
-            Move::RegularMove {
-                0: Coordinate::E5,
-                1: Coordinate::H5,
-                2: Tile::FIRST_QUEEN
-            },
+            Move::RegularMove(Coordinate::E5, Coordinate::A1, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::B2, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::C3, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::D4, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::F6, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::G7, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::H8, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::H2, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::G3, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::F4, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::D6, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::B8, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::C7, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::E1, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::E2, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::E3, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::E4, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::E6, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::E7, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::E8, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::A5, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::B5, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::C5, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::D5, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::F5, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::G5, Tile::FIRST_QUEEN),
+            Move::RegularMove(Coordinate::E5, Coordinate::H5, Tile::FIRST_QUEEN),
         ])
     );
 }
@@ -486,67 +192,143 @@ fn pawn_attack_moves() {
     assert_that!(
         &available_moves,
         contains_in_any_order(vec![
-            Move::PawnAttackMove {
-                0: Coordinate::D5,
-                1: Coordinate::C6
-            },
-            Move::PawnMove {
-                0: Coordinate::D5,
-                1: Coordinate::D6
-            },
-            Move::PawnAttackMove {
-                0: Coordinate::D5,
-                1: Coordinate::E6
-            },
+            Move::PawnAttackMove(Coordinate::D5, Coordinate::C6),
+            Move::PawnMove(Coordinate::D5, Coordinate::D6),
+            Move::PawnAttackMove(Coordinate::D5, Coordinate::E6),
         ])
     );
 }
 
 #[test]
 fn en_passant_first_player_east() {
-    let mut game = Game::from_fen("3k4/2p5/8/1P6/8/8/8/3K4 b - - 0 1");
+    let game = Game::from_fen("3k4/2p5/8/1P6/8/8/8/3K4 b - - 0 1");
 
     let game = game.make_move_san("c5").unwrap_if_ongoing();
 
     assert_that!(
         &game.possible_moves,
-        contains_subset(vec![
-            Move::EnPassant {
-                0: Coordinate::B5,
-                1: Coordinate::C6
-            }
-        ])
+        contains_subset(vec![Move::EnPassant(Coordinate::B5, Coordinate::C6)])
     )
 }
 
 #[test]
 fn en_passant_second_player_west() {
-    let mut game = Game::from_fen("3k4/8/8/8/2p5/8/1P6/3K4 w - - 0 1");
+    let game = Game::from_fen("3k4/8/8/8/2p5/8/1P6/3K4 w - - 0 1");
+
+    let game = game.make_move_san("b4").unwrap_if_ongoing();
+
+    assert_that!(
+        &game.possible_moves,
+        contains_subset(vec![Move::EnPassant(Coordinate::C4, Coordinate::B3)])
+    )
+}
+
+#[test]
+fn given_target_pawn_did_not_move_last_turn_second_player_cannot_en_passant() {
+    let game = Game::from_fen("3k4/8/8/8/1Pp5/8/8/3K4 b - - 0 1").unwrap_if_ongoing();
+
+    assert_that!(
+        &game.possible_moves,
+        not(contains_subset(vec![Move::EnPassant(
+            Coordinate::C4,
+            Coordinate::B3
+        )]))
+    )
+}
+
+#[test]
+fn given_target_pawn_did_not_move_last_turn_cannot_en_passant() {
+    let game = Game::from_fen("3k4/8/8/1Pp5/8/8/8/3K4 w - - 0 1").unwrap_if_ongoing();
+
+    assert_that!(
+        &game.possible_moves,
+        not(contains_subset(vec![Move::EnPassant(
+            Coordinate::B5,
+            Coordinate::C6
+        )]))
+    )
+}
+
+#[test]
+fn en_passant_first_player_west() {
+    let game = Game::from_fen("3k4/2p5/8/3P4/8/8/8/3K4 b - - 0 1");
+
+    let game = game.make_move_san("c5").unwrap_if_ongoing();
+
+    assert_that!(
+        &game.possible_moves,
+        contains_subset(vec![Move::EnPassant(Coordinate::D5, Coordinate::C6)])
+    )
+}
+
+#[test]
+fn en_passant_second_player_east() {
+    let game = Game::from_fen("3k4/8/8/8/p7/8/1P6/3K4 w - - 0 1");
 
     let game = game.make_move_san("b4").unwrap_if_ongoing();
 
+    assert_that!(
+        &game.possible_moves,
+        contains_subset(vec![Move::EnPassant(Coordinate::A4, Coordinate::B3)])
+    )
+}
+
+#[test]
+fn given_target_pawn_moved_one_square_cannot_en_passant() {
+    let game = Game::from_fen("3k4/8/2p5/1P6/8/8/8/3K4 b - - 0 1");
+
+    let game = game.make_move_san("c5").unwrap_if_ongoing();
+
+    assert_that!(
+        &game.possible_moves,
+        not(contains_subset(vec![Move::EnPassant(
+            Coordinate::B5,
+            Coordinate::C6
+        )]))
+    )
+}
+
+#[test]
+fn en_passant_square_is_read_from_fen() {
+    let game = Game::from_fen("3k4/8/8/1Pp5/8/8/8/3K4 w - c6 0 1").unwrap_if_ongoing();
+
+    assert_that!(
+        &game.possible_moves,
+        contains_subset(vec![Move::EnPassant(Coordinate::B5, Coordinate::C6)])
+    )
+}
+
+#[test]
+fn pawn_capturing_onto_last_rank_must_promote() {
+    let game = Game::from_fen("2r1k3/1P6/8/8/8/8/8/4K3 w - - 0 1").unwrap_if_ongoing();
+
     assert_that!(
         &game.possible_moves,
         contains_subset(vec![
-            Move::EnPassant {
-                0: Coordinate::C4,
-                1: Coordinate::B3
-            }
+            Move::PawnAttackPromotion(Coordinate::B7, Coordinate::C8, Tile::FIRST_QUEEN),
+            Move::PawnAttackPromotion(Coordinate::B7, Coordinate::C8, Tile::FIRST_ROOK),
+            Move::PawnAttackPromotion(Coordinate::B7, Coordinate::C8, Tile::FIRST_BISHOP),
+            Move::PawnAttackPromotion(Coordinate::B7, Coordinate::C8, Tile::FIRST_KNIGHT),
         ])
+    );
+    assert_that!(
+        &game.possible_moves,
+        not(contains_subset(vec![Move::PawnAttackMove(
+            Coordinate::B7,
+            Coordinate::C8
+        )]))
     )
 }
 
 #[test]
-fn given_target_pawn_did_not_move_last_turn_cannot_en_passant() {
-    let mut game = Game::from_fen("3k4/8/8/1Pp5/8/8/8/3K4 w - - 0 1").unwrap_if_ongoing();
+fn second_player_pawn_capturing_onto_last_rank_must_promote() {
+    let game = Game::from_fen("4k3/8/8/8/8/8/6p1/4K2R b - - 0 1").unwrap_if_ongoing();
 
     assert_that!(
         &game.possible_moves,
-        not(contains_subset(vec![
-            Move::EnPassant {
-                0: Coordinate::B5,
-                1: Coordinate::C6
-            }
-        ]))
+        contains_subset(vec![
+            Move::PawnAttackPromotion(Coordinate::G2, Coordinate::H1, Tile::SECOND_QUEEN),
+            Move::PawnAttackPromotion(Coordinate::G2, Coordinate::H1, Tile::SECOND_KNIGHT),
+        ])
     )
 }
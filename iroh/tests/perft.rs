@@ -0,0 +1,28 @@
+use iroh::state::GameState;
+use test_case::test_case;
+
+///Counts the positions reached after every sequence of `depth` legal moves, which can be checked
+///against counts from other engines to find move generation bugs
+fn perft(game_state: &GameState, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    game_state
+        .possible_moves
+        .iter()
+        .map(|possible_move| perft(&game_state.make_move(possible_move).unwrap(), depth - 1))
+        .sum()
+}
+
+#[test_case("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", 3, 8902; "starting position")]
+#[test_case("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1", 2, 2039; "castling and pins")]
+#[test_case("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 4, 43238; "en passant")]
+#[test_case("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1", 3, 9467; "promotion by capture")]
+#[test_case("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8", 2, 1486; "promotion with check")]
+fn perft_matches_known_counts(fen: &str, depth: u8, expected_nodes: u64) {
+    let game_state = GameState::from_fen(fen);
+
+    let result = perft(&game_state, depth);
+
+    assert_eq!(expected_nodes, result);
+}
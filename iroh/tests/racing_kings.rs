@@ -0,0 +1,73 @@
+use iroh::game::Game;
+use iroh::state::variant::Variant;
+
+#[test]
+fn new_racing_kings_game_starts_on_first_two_ranks() {
+    let game = Game::with_variant(Variant::RacingKings);
+
+    let result = game.generate_fen().unwrap();
+
+    assert_eq!("8/8/8/8/8/8/krbnNBRK/qrbnNBRQ w - - 0 1", result);
+}
+
+#[test]
+fn cannot_give_check() {
+    let game = Game::from_fen_with_variant("8/8/8/k7/8/8/8/1R5K w - - 0 1", Variant::RacingKings);
+
+    let result = game.make_move_san("Ra1");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn second_player_reaching_last_rank_wins() {
+    let game = Game::from_fen_with_variant("8/k7/8/8/8/8/8/K7 b - - 0 1", Variant::RacingKings);
+
+    let result = game.make_move_san("Ka8");
+
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: false,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn first_player_reaching_last_rank_wins_if_second_player_cannot_follow() {
+    let game = Game::from_fen_with_variant("8/6K1/8/8/8/8/k7/8 w - - 0 1", Variant::RacingKings);
+
+    let result = game.make_move_san("Kg8");
+
+    assert_eq!("1. Kg8 1-0", result.generate_pgn().unwrap());
+}
+
+#[test]
+fn second_player_reaching_last_rank_straight_after_first_player_draws() {
+    let mut game =
+        Game::from_fen_with_variant("8/k5K1/8/8/8/8/8/8 w - - 0 1", Variant::RacingKings);
+
+    game = game.make_move_san("Kg8");
+    assert!(matches!(game, Game::Ongoing { .. }));
+    game = game.make_move_san("Ka8");
+
+    assert!(matches!(game, Game::Draw { .. }));
+}
+
+#[test]
+fn first_player_wins_if_second_player_does_not_follow() {
+    let mut game =
+        Game::from_fen_with_variant("8/k5K1/8/8/8/8/8/8 w - - 0 1", Variant::RacingKings);
+
+    game = game.make_move_san("Kg8");
+    game = game.make_move_san("Ka6");
+
+    assert!(matches!(
+        game,
+        Game::Win {
+            is_first_player_win: true,
+            ..
+        }
+    ));
+}
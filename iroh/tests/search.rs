@@ -1,5 +1,31 @@
 use iroh::game::Game;
-use iroh::search::search;
+use iroh::heuristics::weightings::Weightings;
+use iroh::heuristics::HeuristicType;
+use iroh::moves::Move;
+use iroh::search::evaluation::{Evaluation, Score};
+use iroh::search::extensions::SearchExtensions;
+use iroh::search::mate_solver::{find_mate, MateSolution};
+use iroh::search::mcts::{LeafEvaluation, MctsSearcher, PlayoutPolicy};
+use iroh::search::no_move_error::NoMoveError;
+use iroh::search::ponder_handle::PonderHandle;
+use iroh::search::search_config::SearchConfig;
+use iroh::search::search_limits::SearchLimits;
+use iroh::search::search_observer::{NullObserver, SearchObserver};
+use iroh::search::search_options::SearchOptions;
+use iroh::search::searcher::{play_out, AlphaBetaSearcher, Searcher};
+use iroh::search::skill_level::{SkillLevel, MAX_SKILL_LEVEL};
+use iroh::search::stop_handle::StopHandle;
+use iroh::search::time_management::TimeControl;
+use iroh::search::transposition_table::TranspositionTable;
+use iroh::search::{
+    ponder_game, search, search_with_limits, search_with_options, search_with_table,
+};
+use iroh::state::variant::Variant;
+use std::thread;
+use std::time::{Duration, Instant};
+
+///How far past its time limit a search may finish, for the last node and the thread to return
+const TIME_LIMIT_SLACK: Duration = Duration::from_millis(100);
 
 macro_rules! search_test_inner {
     ($name:ident,$fen:expr,$expected_san:expr) => {
@@ -7,7 +33,7 @@ macro_rules! search_test_inner {
         fn $name() {
             let mut state = Game::from_fen($fen);
 
-            let result = search(&mut state);
+            let result = search(&mut state).unwrap();
 
             assert_eq!(String::from($expected_san), result.best_move);
         }
@@ -32,5 +58,910 @@ search_tests! {
     {second_player_gain_9_material_better_than_gaining_5,"k7/8/8/3q4/2Q5/5R2/K7/8 b - - 0 1","Qxc4"}
     {checkmate_in_one,"3k4/7R/8/R7/8/8/8/3K4 w - - 0 1","Ra8"}
     {checkmate_in_one_as_second_player,"3k4/8/8/r7/8/8/7r/3K4 b - - 0 1","Ra1"}
+    {recapture_rather_than_lose_material,"3k4/8/8/8/8/2n5/1P6/4K3 w - - 0 1","bxc3"}
     {discovered_check,"r3kb1r/ppq2ppp/2p5/3pN3/3P4/8/PPPQ1PPP/R3R1K1 w kq - 0 1","Ng6"}
 }
+
+#[test]
+fn search_reports_each_completed_depth() {
+    let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
+    let limits = SearchLimits::new().with_max_depth(3);
+    let mut reported_depths = vec![];
+
+    let result = search_with_limits(&mut game, &limits, |evaluation: &Evaluation| {
+        reported_depths.push(evaluation.depth)
+    })
+    .unwrap();
+
+    assert_eq!(vec![1, 2, 3], reported_depths);
+    assert_eq!(3, result.depth);
+    assert_eq!("exd5", result.best_move);
+}
+
+#[test]
+fn deeper_search_visits_more_nodes() {
+    let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
+
+    let shallow = search_with_limits(
+        &mut game,
+        &SearchLimits::new().with_max_depth(1),
+        NullObserver,
+    )
+    .unwrap();
+    let deep = search_with_limits(
+        &mut game,
+        &SearchLimits::new().with_max_depth(2),
+        NullObserver,
+    )
+    .unwrap();
+
+    assert_eq!(1, shallow.depth);
+    assert!(deep.nodes > shallow.nodes);
+}
+
+#[test]
+fn given_node_limit_returns_best_move_of_last_completed_depth() {
+    let mut game = Game::from_fen("8/k7/3q4/5p2/6P1/3Q4/K7/8 w - - 0 1");
+    let limits = SearchLimits::new().with_max_depth(10).with_max_nodes(500);
+    let mut completed = vec![];
+
+    let result = search_with_limits(&mut game, &limits, |evaluation: &Evaluation| {
+        completed.push(evaluation.clone())
+    })
+    .unwrap();
+
+    let last_completed = completed.last().unwrap();
+    assert!(result.depth < 10);
+    assert_eq!(last_completed.depth, result.depth);
+    assert_eq!(last_completed.best_move, result.best_move);
+    assert_eq!("Qxd6", result.best_move);
+}
+
+#[test]
+fn given_move_time_search_stops_early() {
+    let mut game = Game::from_fen("r3kb1r/ppq2ppp/2p5/3pN3/3P4/8/PPPQ1PPP/R3R1K1 w kq - 0 1");
+    let move_time = Duration::from_millis(200);
+    let limits = SearchLimits::new()
+        .with_max_depth(20)
+        .with_move_time(move_time);
+
+    let start = Instant::now();
+    let result = search_with_limits(&mut game, &limits, NullObserver).unwrap();
+
+    assert!(start.elapsed() < move_time + TIME_LIMIT_SLACK);
+    assert!(result.depth < 20);
+}
+
+#[test]
+fn given_time_control_search_stays_within_hard_budget() {
+    let mut game = Game::from_fen("r3kb1r/ppq2ppp/2p5/3pN3/3P4/8/PPPQ1PPP/R3R1K1 w kq - 0 1");
+    let time_control = TimeControl::fischer(Duration::from_secs(3), Duration::ZERO);
+    let limits = SearchLimits::new()
+        .with_max_depth(20)
+        .with_time_control(time_control);
+
+    let start = Instant::now();
+    let result = search_with_limits(&mut game, &limits, NullObserver).unwrap();
+
+    assert!(start.elapsed() < time_control.allocate(true).hard + TIME_LIMIT_SLACK);
+    assert!(result.depth < 20);
+}
+
+#[test]
+fn search_fills_transposition_table() {
+    let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
+    let mut table = TranspositionTable::new(1);
+
+    let result =
+        search_with_table(&mut game, &SearchLimits::new(), &mut table, NullObserver).unwrap();
+
+    assert_eq!("exd5", result.best_move);
+    assert!(!table.is_empty());
+}
+
+#[test]
+fn reusing_transposition_table_visits_fewer_nodes() {
+    let mut game = Game::from_fen("8/k7/3q4/5p2/6P1/3Q4/K7/8 w - - 0 1");
+    let mut table = TranspositionTable::new(1);
+    let limits = SearchLimits::new();
+
+    let first = search_with_table(&mut game, &limits, &mut table, NullObserver).unwrap();
+    let second = search_with_table(&mut game, &limits, &mut table, NullObserver).unwrap();
+
+    assert_eq!(first.best_move, second.best_move);
+    assert!(second.nodes < first.nodes);
+}
+
+#[test]
+fn cleared_transposition_table_searches_from_scratch() {
+    let mut game = Game::from_fen("8/k7/3q4/5p2/6P1/3Q4/K7/8 w - - 0 1");
+    let mut table = TranspositionTable::new(1);
+    let limits = SearchLimits::new();
+
+    let first = search_with_table(&mut game, &limits, &mut table, NullObserver).unwrap();
+    table.clear();
+    let second = search_with_table(&mut game, &limits, &mut table, NullObserver).unwrap();
+
+    assert!(!table.is_empty());
+    assert_eq!(first.nodes, second.nodes);
+}
+
+#[test]
+fn does_not_capture_defended_piece_at_depth_limit() {
+    let mut game = Game::from_fen("3k4/2p5/3n4/8/8/3Q4/8/3K4 w - - 0 1");
+    let limits = SearchLimits::new().with_max_depth(1);
+
+    let result = search_with_limits(&mut game, &limits, NullObserver).unwrap();
+
+    assert_ne!("Qxd6", result.best_move);
+}
+
+#[test]
+fn captures_hanging_piece_at_depth_limit() {
+    let mut game = Game::from_fen("3k4/8/3n4/8/8/3Q4/8/3K4 w - - 0 1");
+    let limits = SearchLimits::new().with_max_depth(1);
+
+    let result = search_with_limits(&mut game, &limits, NullObserver).unwrap();
+
+    assert_eq!("Qxd6", result.best_move);
+}
+
+#[test]
+fn does_not_capture_pawn_defended_by_pawn_at_depth_limit() {
+    let mut game = Game::from_fen("3k4/8/2p5/3p4/8/8/8/3RK3 w - - 0 1");
+    let limits = SearchLimits::new().with_max_depth(1);
+
+    let result = search_with_limits(&mut game, &limits, NullObserver).unwrap();
+
+    assert_ne!("Rxd5", result.best_move);
+}
+
+#[test]
+fn quiescence_checks_search_more_nodes() {
+    let fen = "r6k/8/3n4/8/8/3Q4/8/7K w - - 0 1";
+    let limits = SearchLimits::new().with_max_depth(1);
+    let mut without_checks = Game::from_fen(fen);
+    let mut with_checks = Game::from_fen(fen);
+
+    let result_without_checks = search_with_options(
+        &mut without_checks,
+        &limits,
+        &SearchOptions::new(),
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+    let result_with_checks = search_with_options(
+        &mut with_checks,
+        &limits,
+        &SearchOptions::new().with_quiescence_checks(true),
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+
+    assert!(result_with_checks.nodes > result_without_checks.nodes);
+}
+
+#[test]
+fn check_extensions_find_mate_beyond_search_depth() {
+    let mut game = Game::from_fen("r1b3kr/ppp1Bp1p/1b6/n2P4/2p3q1/2Q2N2/P4PPP/RN2R1K1 w - - 1 0");
+    let limits = SearchLimits::new().with_max_depth(2);
+
+    let result = search_with_options(
+        &mut game,
+        &limits,
+        &SearchOptions::new(),
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+
+    assert_eq!("Qxh8", result.best_move);
+    assert_eq!(Score::Mate(3), result.score);
+}
+
+#[test]
+fn without_extensions_mate_is_beyond_search_depth() {
+    let mut game = Game::from_fen("r1b3kr/ppp1Bp1p/1b6/n2P4/2p3q1/2Q2N2/P4PPP/RN2R1K1 w - - 1 0");
+    let limits = SearchLimits::new().with_max_depth(2);
+
+    let result = search_with_options(
+        &mut game,
+        &limits,
+        &SearchOptions::new().with_extensions(SearchExtensions::none()),
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+
+    assert!(matches!(result.score, Score::Centipawns(_)));
+}
+
+#[test]
+fn move_ordering_visits_fewer_nodes() {
+    let fen = "r3k3/1p6/3q4/8/8/3Q4/1P6/R3K3 w - - 0 1";
+    let limits = SearchLimits::new().with_max_depth(2);
+    let mut unordered = Game::from_fen(fen);
+    let mut ordered = Game::from_fen(fen);
+
+    let unordered_result = search_with_options(
+        &mut unordered,
+        &limits,
+        &SearchOptions::new().with_move_ordering(false),
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+    let ordered_result = search_with_options(
+        &mut ordered,
+        &limits,
+        &SearchOptions::new(),
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+
+    assert!(ordered_result.nodes < unordered_result.nodes);
+}
+
+#[test]
+fn winning_material_scores_positive_centipawns() {
+    let mut game = Game::from_fen("3k4/8/3q4/8/8/3Q4/8/3K4 w - - 0 1");
+
+    let result = search(&mut game).unwrap();
+
+    assert!(matches!(result.score, Score::Centipawns(centipawns) if centipawns >= 900));
+}
+
+#[test]
+fn score_is_from_point_of_view_of_player_to_move() {
+    let mut game = Game::from_fen("3k4/8/3Q4/8/8/3q4/8/3K4 b - - 0 1");
+
+    let result = search(&mut game).unwrap();
+
+    assert!(matches!(result.score, Score::Centipawns(centipawns) if centipawns >= 900));
+}
+
+#[test]
+fn checkmate_in_one_scores_mate_in_one() {
+    let mut game = Game::from_fen("3k4/7R/8/R7/8/8/8/3K4 w - - 0 1");
+
+    let result = search(&mut game).unwrap();
+
+    assert_eq!(Score::Mate(1), result.score);
+    assert_eq!(vec!["Ra8"], result.principal_variation_san());
+}
+
+#[test]
+fn principal_variation_starts_with_best_move() {
+    let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
+    let limits = SearchLimits::new().with_max_depth(3);
+
+    let result = search_with_limits(&mut game, &limits, NullObserver).unwrap();
+
+    let principal_variation = result.principal_variation_san();
+    assert_eq!(result.best_move, principal_variation[0]);
+    assert_eq!(3, principal_variation.len());
+}
+
+#[test]
+fn search_reports_selective_depth_and_speed() {
+    let mut game = Game::from_fen("3k4/8/2p5/3p4/8/8/8/3RK3 w - - 0 1");
+    let limits = SearchLimits::new().with_max_depth(1);
+
+    let result = search_with_limits(&mut game, &limits, NullObserver).unwrap();
+
+    assert!(result.seldepth > result.depth);
+    assert!(result.elapsed > Duration::ZERO);
+    assert!(result.nodes_per_second > 0);
+}
+
+#[test]
+fn checkmate_in_two_scores_mate_in_two() {
+    let mut game = Game::from_fen("k7/8/2K5/8/8/8/8/7R w - - 0 1");
+
+    let result = search(&mut game).unwrap();
+
+    assert_eq!(Some(2), result.score.mate_in());
+    assert_eq!(3, result.principal_variation.len());
+}
+
+#[test]
+fn being_checkmated_next_move_scores_negative_mate_in_one() {
+    let mut game = Game::from_fen("k7/8/1K6/8/8/8/8/7R b - - 0 1");
+
+    let result = search(&mut game).unwrap();
+
+    assert_eq!(Score::Mate(-1), result.score);
+}
+
+fn nodes_searched(fen: &str, max_depth: u8, options: &SearchOptions) -> u64 {
+    let mut game = Game::from_fen(fen);
+    let limits = SearchLimits::new().with_max_depth(max_depth);
+
+    search_with_options(
+        &mut game,
+        &limits,
+        options,
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap()
+    .nodes
+}
+
+#[test]
+fn null_move_pruning_visits_fewer_nodes() {
+    let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3";
+    let without = SearchOptions::new().with_null_move_pruning(false);
+
+    let pruned = nodes_searched(fen, 4, &SearchOptions::new());
+    let unpruned = nodes_searched(fen, 4, &without);
+
+    assert!(pruned < unpruned);
+}
+
+#[test]
+fn late_move_reductions_visit_fewer_nodes() {
+    let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3";
+    let without = SearchOptions::new().with_late_move_reductions(false);
+
+    let reduced = nodes_searched(fen, 4, &SearchOptions::new());
+    let unreduced = nodes_searched(fen, 4, &without);
+
+    assert!(reduced < unreduced);
+}
+
+#[test]
+fn principal_variation_search_visits_fewer_nodes() {
+    let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3";
+    let without = SearchOptions::new().with_principal_variation_search(false);
+
+    let with_pvs = nodes_searched(fen, 4, &SearchOptions::new());
+    let without_pvs = nodes_searched(fen, 4, &without);
+
+    assert!(with_pvs < without_pvs);
+}
+
+#[test]
+fn aspiration_windows_visit_fewer_nodes() {
+    let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3";
+    //Extensions make the tree too uneven for the node counts to compare reliably
+    let with = SearchOptions::new().with_extensions(SearchExtensions::none());
+    let without = with.clone().with_aspiration_windows(false);
+
+    let with_windows = nodes_searched(fen, 4, &with);
+    let without_windows = nodes_searched(fen, 4, &without);
+
+    assert!(with_windows < without_windows);
+}
+
+#[test]
+fn aspiration_windows_widen_without_material_weight() {
+    let weightings = Weightings::new().push(HeuristicType::Material, 0.0);
+    let config = SearchConfig::new()
+        .with_weightings(weightings)
+        .with_options(SearchOptions::new().with_aspiration_windows(true));
+    let mut searcher = AlphaBetaSearcher::new(config);
+    let mut game = Game::new();
+
+    let result = searcher
+        .search(
+            &mut game,
+            &SearchLimits::new().with_max_depth(3),
+            &mut NullObserver,
+        )
+        .unwrap();
+
+    assert_eq!(3, result.depth);
+}
+
+#[test]
+fn multi_pv_finds_best_moves_in_order() {
+    let mut game = Game::from_fen("8/8/1rp5/4q3/3Q4/3R4/8/8 w - - 0 1");
+    let options = SearchOptions::new().with_multi_pv(3);
+
+    let result = search_with_options(
+        &mut game,
+        &SearchLimits::new(),
+        &options,
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+
+    let first_moves: Vec<String> = result
+        .lines
+        .iter()
+        .map(|line| line.principal_variation_san()[0].clone())
+        .collect();
+    assert_eq!(3, result.lines.len());
+    assert_eq!("Qxe5", first_moves[0]);
+    assert_eq!(result.best_move, first_moves[0]);
+    assert_eq!(result.score, result.lines[0].score);
+    assert!(!first_moves[1..].contains(&first_moves[0]));
+    assert_ne!(first_moves[1], first_moves[2]);
+}
+
+#[test]
+fn multi_pv_lines_are_scored_best_first() {
+    let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
+    let options = SearchOptions::new().with_multi_pv(3);
+
+    let result = search_with_options(
+        &mut game,
+        &SearchLimits::new(),
+        &options,
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+
+    let centipawns: Vec<i32> = result
+        .lines
+        .iter()
+        .map(|line| match line.score {
+            Score::Centipawns(centipawns) => centipawns,
+            Score::Mate(_) => panic!("No mate in this position"),
+        })
+        .collect();
+    assert!(centipawns.windows(2).all(|pair| pair[0] >= pair[1]));
+}
+
+#[test]
+fn multi_pv_returns_no_more_lines_than_legal_moves() {
+    let mut game = Game::from_fen("k7/8/1K6/8/8/8/8/7R b - - 0 1");
+    let options = SearchOptions::new().with_multi_pv(3);
+
+    let result = search_with_options(
+        &mut game,
+        &SearchLimits::new(),
+        &options,
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+
+    assert_eq!(1, result.lines.len());
+}
+
+#[test]
+fn multi_threaded_search_finds_same_tactic() {
+    let mut game = Game::from_fen("8/8/1rp5/4q3/3Q4/3R4/8/8 w - - 0 1");
+    let options = SearchOptions::new().with_threads(4);
+
+    let result = search_with_options(
+        &mut game,
+        &SearchLimits::new(),
+        &options,
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap();
+
+    assert_eq!("Qxe5", result.best_move);
+    assert_eq!(3, result.depth);
+}
+
+#[test]
+fn multi_threaded_search_reports_every_main_thread_depth() {
+    let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
+    let options = SearchOptions::new().with_threads(2);
+    let mut reported_depths = vec![];
+
+    search_with_options(
+        &mut game,
+        &SearchLimits::new(),
+        &options,
+        &mut TranspositionTable::default(),
+        |evaluation: &Evaluation| reported_depths.push(evaluation.depth),
+    )
+    .unwrap();
+
+    assert_eq!(vec![1, 2, 3], reported_depths);
+}
+
+#[test]
+fn search_stopped_before_starting_still_returns_a_legal_move() {
+    let mut game =
+        Game::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3");
+    let stop_handle = StopHandle::new();
+    stop_handle.stop();
+    let limits = SearchLimits::new()
+        .with_max_depth(5)
+        .with_stop_handle(stop_handle);
+
+    let result = search_with_limits(&mut game, &limits, NullObserver).unwrap();
+
+    assert_eq!(0, result.depth);
+    assert!(matches!(
+        game.make_move_san(&result.best_move),
+        Game::Ongoing { .. }
+    ));
+}
+
+#[test]
+fn search_can_be_stopped_from_another_thread() {
+    let mut game =
+        Game::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3");
+    let stop_handle = StopHandle::new();
+    let limits = SearchLimits::new()
+        .with_max_depth(20)
+        .with_stop_handle(stop_handle.clone());
+    let start = Instant::now();
+
+    let result = thread::scope(|scope| {
+        let search = scope.spawn(|| search_with_limits(&mut game, &limits, NullObserver).unwrap());
+        thread::sleep(Duration::from_millis(100));
+        stop_handle.stop();
+        search.join().unwrap()
+    });
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(result.depth < 20);
+    assert!(!result.best_move.is_empty());
+}
+
+#[derive(Default)]
+struct RecordingObserver {
+    root_moves: Vec<(u8, usize)>,
+    depths: Vec<u8>,
+}
+
+impl SearchObserver for RecordingObserver {
+    fn on_iteration_complete(&mut self, evaluation: &Evaluation) {
+        self.depths.push(evaluation.depth);
+    }
+
+    fn on_root_move(&mut self, depth: u8, _possible_move: &Move, move_number: usize) {
+        self.root_moves.push((depth, move_number));
+    }
+}
+
+#[test]
+fn observer_is_told_about_each_root_move_and_iteration() {
+    let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
+    let limits = SearchLimits::new().with_max_depth(2);
+    let mut observer = RecordingObserver::default();
+
+    search_with_limits(&mut game, &limits, &mut observer as &mut dyn SearchObserver).unwrap();
+
+    assert_eq!(vec![1, 2], observer.depths);
+    assert_eq!(Some(&(1, 1)), observer.root_moves.first());
+    assert!(observer.root_moves.contains(&(2, 2)));
+}
+
+#[test]
+fn pondering_ignores_time_limits_until_ponder_hit() {
+    let mut game =
+        Game::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3");
+    let ponder_handle = PonderHandle::new();
+    let limits = SearchLimits::new()
+        .with_max_depth(20)
+        .with_move_time(Duration::from_millis(20))
+        .with_ponder_handle(ponder_handle.clone());
+
+    let result = thread::scope(|scope| {
+        let search = scope.spawn(|| search_with_limits(&mut game, &limits, NullObserver).unwrap());
+        thread::sleep(Duration::from_millis(300));
+        assert!(!search.is_finished());
+        ponder_handle.ponder_hit();
+        search.join().unwrap()
+    });
+
+    assert!(result.elapsed >= Duration::from_millis(250));
+    assert!(result.elapsed < Duration::from_secs(5));
+}
+
+#[test]
+fn ponder_miss_stops_the_search() {
+    let mut game =
+        Game::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3");
+    let stop_handle = StopHandle::new();
+    let limits = SearchLimits::new()
+        .with_max_depth(20)
+        .with_move_time(Duration::from_millis(20))
+        .with_ponder_handle(PonderHandle::new())
+        .with_stop_handle(stop_handle.clone());
+
+    let result = thread::scope(|scope| {
+        let search = scope.spawn(|| search_with_limits(&mut game, &limits, NullObserver).unwrap());
+        thread::sleep(Duration::from_millis(100));
+        stop_handle.stop();
+        search.join().unwrap()
+    });
+
+    assert!(result.elapsed < Duration::from_secs(5));
+}
+
+#[test]
+fn ponder_game_is_after_the_best_move_and_expected_reply() {
+    let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
+    let evaluation = search_with_limits(
+        &mut game,
+        &SearchLimits::new().with_max_depth(3),
+        NullObserver,
+    )
+    .unwrap();
+
+    let result = ponder_game(&game, &evaluation).unwrap();
+
+    let expected = game
+        .make_move(&evaluation.principal_variation[0])
+        .make_move(&evaluation.principal_variation[1]);
+    assert_eq!(expected.generate_fen(), result.generate_fen());
+}
+
+#[test]
+fn ponder_game_needs_an_expected_reply() {
+    let mut game = Game::from_fen("3k4/8/8/3p4/4P3/8/8/3K4 w - - 0 1");
+    let evaluation = search_with_limits(
+        &mut game,
+        &SearchLimits::new().with_max_depth(1),
+        NullObserver,
+    )
+    .unwrap();
+
+    assert!(ponder_game(&game, &evaluation).is_none());
+}
+
+#[test]
+fn search_when_checkmated_has_no_move() {
+    let mut game = Game::from_fen("k6R/8/1K6/8/8/8/8/8 b - - 0 1");
+
+    let result = search(&mut game);
+
+    assert_eq!(Err(NoMoveError::Checkmate), result);
+}
+
+#[test]
+fn search_when_stalemated_has_no_move() {
+    let mut game = Game::from_fen("k7/8/1QK5/8/8/8/8/8 b - - 0 1");
+
+    let result = search(&mut game);
+
+    assert_eq!(Err(NoMoveError::Stalemate), result);
+}
+
+#[test]
+fn search_when_game_is_over_has_no_move() {
+    let mut game =
+        Game::from_fen_with_variant("k7/8/8/3K4/8/8/8/8 b - - 0 1", Variant::KingOfTheHill);
+
+    let result = search(&mut game);
+
+    assert_eq!(Err(NoMoveError::GameOver), result);
+}
+
+fn play(fen: &str, sans: &[&str]) -> Game {
+    sans.iter()
+        .fold(Game::from_fen(fen), |game, san| game.make_move_san(san))
+}
+
+#[test]
+fn losing_side_repeats_the_position_to_draw() {
+    let mut game = play("3k4/8/8/8/8/8/8/R2K4 w - - 0 1", &["Ra2", "Kc8", "Ra1"]);
+
+    let result = search(&mut game).unwrap();
+
+    assert_eq!("Kd8", result.best_move);
+    assert_eq!(Score::Centipawns(0), result.score);
+}
+
+fn search_with_contempt(game: &mut Game, contempt: i32) -> String {
+    search_with_options(
+        game,
+        &SearchLimits::new(),
+        &SearchOptions::new().with_contempt(contempt),
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap()
+    .best_move
+}
+
+#[test]
+fn negative_contempt_seeks_a_repetition() {
+    let mut game = play("4k3/4p3/8/8/8/8/4P3/4K3 w - - 0 1", &["Kd1", "Kd8", "Ke1"]);
+
+    let result = search_with_contempt(&mut game, -500);
+
+    assert_eq!("Ke8", result);
+}
+
+#[test]
+fn positive_contempt_avoids_a_repetition() {
+    let mut game = play("4k3/4p3/8/8/8/8/4P3/4K3 w - - 0 1", &["Kd1", "Kd8", "Ke1"]);
+
+    let result = search_with_contempt(&mut game, 500);
+
+    assert_ne!("Ke8", result);
+}
+
+fn search_at_skill_level(
+    fen: &str,
+    limits: &SearchLimits,
+    options: SearchOptions,
+    skill_level: SkillLevel,
+) -> Evaluation {
+    search_with_options(
+        &mut Game::from_fen(fen),
+        limits,
+        &options.with_skill_level(skill_level),
+        &mut TranspositionTable::default(),
+        NullObserver,
+    )
+    .unwrap()
+}
+
+#[test]
+fn weakest_skill_level_searches_one_ply() {
+    let result = search_at_skill_level(
+        "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3",
+        &SearchLimits::new().with_max_depth(4),
+        SearchOptions::new(),
+        SkillLevel::new(0).with_seed(1),
+    );
+
+    assert_eq!(1, result.depth);
+    assert_eq!(1, result.lines.len());
+}
+
+#[test]
+fn full_strength_skill_level_plays_best_move() {
+    let result = search_at_skill_level(
+        "8/k7/3q4/5p2/6P1/3Q4/K7/8 w - - 0 1",
+        &SearchLimits::new(),
+        SearchOptions::new(),
+        SkillLevel::new(MAX_SKILL_LEVEL),
+    );
+
+    assert_eq!("Qxd6", result.best_move);
+}
+
+#[test]
+fn mid_skill_level_never_allows_mate_in_one() {
+    for seed in 0..20 {
+        let result = search_at_skill_level(
+            "7k/5ppp/8/8/8/8/5PPP/3R2K1 b - - 0 1",
+            &SearchLimits::new(),
+            SearchOptions::new().with_multi_pv(10),
+            SkillLevel::new(5).with_seed(seed),
+        );
+
+        assert!(result
+            .lines
+            .iter()
+            .any(|line| line.score == Score::Mate(-1)));
+        assert_ne!(Score::Mate(-1), result.score);
+        assert_eq!(result.lines[0].score, result.score);
+    }
+}
+
+#[test]
+fn mid_skill_level_finishes_two_plies_despite_node_cap() {
+    for seed in 0..20 {
+        let result = search_at_skill_level(
+            "7k/5ppp/8/8/8/8/5PPP/3R2K1 b - - 0 1",
+            &SearchLimits::new().with_max_nodes(50),
+            SearchOptions::new(),
+            SkillLevel::new(5).with_seed(seed),
+        );
+
+        assert_eq!(2, result.depth);
+        assert_ne!(Score::Mate(-1), result.score);
+    }
+}
+
+#[test]
+fn mate_solver_finds_mate_beyond_search_depth() {
+    let game = Game::from_fen("8/8/8/7k/R7/1R6/8/2K5 w - - 0 1");
+
+    let result = find_mate(&game, 4, &SearchLimits::new()).unwrap();
+
+    let MateSolution::Mate(line) = result else {
+        panic!("Expected a mate, found {result:?}");
+    };
+    assert_eq!(4, line.len().div_ceil(2));
+    let final_position = line
+        .iter()
+        .fold(game, |game, possible_move| game.make_move(possible_move));
+    assert!(matches!(
+        final_position,
+        Game::Win {
+            is_first_player_win: true,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn mate_solver_proves_no_mate_within_limit() {
+    let game = Game::from_fen("8/8/8/7k/R7/1R6/8/2K5 w - - 0 1");
+
+    let result = find_mate(&game, 3, &SearchLimits::new()).unwrap();
+
+    assert_eq!(MateSolution::NoMate, result);
+}
+
+#[test]
+fn searchers_can_be_swapped_behind_the_trait() {
+    let weightings = Weightings::new().push(HeuristicType::Material, 22.0);
+    let mut searchers: Vec<Box<dyn Searcher>> = vec![
+        Box::new(AlphaBetaSearcher::default()),
+        Box::new(AlphaBetaSearcher::new(
+            SearchConfig::new()
+                .with_weightings(weightings)
+                .with_options(SearchOptions::new().with_move_ordering(false)),
+        )),
+    ];
+
+    for searcher in searchers.iter_mut() {
+        let mut game = Game::from_fen("3k4/8/3q4/8/8/3Q4/8/3K4 w - - 0 1");
+
+        let result = searcher
+            .search(&mut game, &SearchLimits::new(), &mut NullObserver)
+            .unwrap();
+
+        assert_eq!("Qxd6", result.best_move);
+    }
+}
+
+#[test]
+fn search_without_material_weight_still_scores() {
+    let weightings = Weightings::new().push(HeuristicType::Material, 0.0);
+    let mut searcher = AlphaBetaSearcher::new(SearchConfig::new().with_weightings(weightings));
+    let mut game = Game::new();
+
+    let result = searcher
+        .search(
+            &mut game,
+            &SearchLimits::new().with_max_depth(1),
+            &mut NullObserver,
+        )
+        .unwrap();
+
+    assert!(matches!(result.score, Score::Centipawns(_)));
+}
+
+#[test]
+fn mcts_searcher_can_replace_alpha_beta() {
+    let mut searcher: Box<dyn Searcher> = Box::new(
+        MctsSearcher::default()
+            .with_exploration(1.0)
+            .with_playout_policy(PlayoutPolicy::HeuristicGuided)
+            .with_playout_plies(2)
+            .with_seed(11),
+    );
+    let mut game = Game::from_fen("8/k7/3q4/5p2/6P1/3Q4/K7/8 w - - 0 1");
+
+    let result = searcher
+        .search(
+            &mut game,
+            &SearchLimits::new().with_max_nodes(1000),
+            &mut NullObserver,
+        )
+        .unwrap();
+
+    assert_eq!("Qxd6", result.best_move);
+}
+
+#[test]
+fn mcts_plays_against_alpha_beta() {
+    let mut mcts = MctsSearcher::default()
+        .with_leaf_evaluation(LeafEvaluation::Heuristics)
+        .with_seed(5);
+    let mut alpha_beta = AlphaBetaSearcher::default();
+
+    let result = play_out(
+        Game::new(),
+        &mut mcts,
+        &mut alpha_beta,
+        &SearchLimits::new().with_max_depth(2).with_max_nodes(200),
+        4,
+    );
+
+    assert!(matches!(result, Game::Ongoing { .. }));
+    assert!(result.generate_pgn().unwrap().contains("2. "));
+}
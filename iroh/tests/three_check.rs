@@ -0,0 +1,74 @@
+use iroh::game::Game;
+use iroh::search::search;
+use iroh::state::variant::Variant;
+
+#[test]
+fn new_three_check_game_has_given_no_checks() {
+    let game = Game::with_variant(Variant::ThreeCheck);
+
+    let result = game.generate_fen().unwrap();
+
+    assert_eq!(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 +0+0",
+        result
+    );
+}
+
+#[test]
+fn giving_check_is_counted() {
+    let mut game = Game::with_variant(Variant::ThreeCheck);
+
+    game = game.make_move_san("e4");
+    game = game.make_move_san("f6");
+    game = game.make_move_san("Qh5");
+
+    assert_eq!(
+        "rnbqkbnr/ppppp1pp/5p2/7Q/4P3/8/PPPP1PPP/RNB1KBNR b KQkq - 0 1 +1+0",
+        game.generate_fen().unwrap()
+    );
+}
+
+#[test]
+fn third_check_wins() {
+    let game =
+        Game::from_fen_with_variant("4k3/8/8/8/8/8/8/R3K3 w - - 0 1 +2+0", Variant::ThreeCheck);
+
+    let result = game.make_move_san("Ra8");
+
+    assert_eq!("1. Ra8 1-0", result.generate_pgn().unwrap());
+}
+
+#[test]
+fn second_player_third_check_wins() {
+    let game =
+        Game::from_fen_with_variant("r3k3/8/8/8/8/8/8/4K3 b - - 0 1 +1+2", Variant::ThreeCheck);
+
+    let result = game.make_move_san("Ra1");
+
+    assert!(matches!(
+        result,
+        Game::Win {
+            is_first_player_win: false,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn checks_do_not_end_standard_game() {
+    let game = Game::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1 +2+0");
+
+    let result = game.make_move_san("Ra8");
+
+    assert!(matches!(result, Game::Ongoing { .. }));
+}
+
+#[test]
+fn search_finds_winning_third_check() {
+    let mut game =
+        Game::from_fen_with_variant("4k3/8/8/3q4/8/8/8/R3K3 w - - 0 1 +2+0", Variant::ThreeCheck);
+
+    let result = search(&mut game).unwrap();
+
+    assert_eq!("Ra8", result.best_move);
+}